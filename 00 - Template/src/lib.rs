@@ -1 +1,24 @@
+use std::fmt::Display;
 
+pub struct Template;
+
+impl toolbox::Solution for Template {
+    const DAY: u8 = 0;
+
+    type Input = String;
+    type Err = toolbox::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok("Hello, world!")
+    }
+
+    fn part2(_input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok("Hello, world!")
+    }
+}
+
+pub const SOLVERS: &[toolbox::Solver] = &toolbox::solvers::<Template>();
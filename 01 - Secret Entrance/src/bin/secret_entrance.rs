@@ -1,6 +1,6 @@
-fn main() {
-    println!(
-        "{}",
-        secret_entrance::calculate_password(&mut std::io::stdin().lock())
-    );
+fn main() -> std::process::ExitCode {
+    toolbox::streaming_main::<secret_entrance::SecretEntrance, _>(
+        1,
+        secret_entrance::calculate_password,
+    )
 }
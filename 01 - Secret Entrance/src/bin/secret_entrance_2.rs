@@ -1,6 +1,6 @@
-fn main() {
-    println!(
-        "{}",
-        secret_entrance::calculate_password_2(&mut std::io::stdin().lock())
-    );
+fn main() -> std::process::ExitCode {
+    toolbox::streaming_main::<secret_entrance::SecretEntrance, _>(
+        2,
+        secret_entrance::calculate_password_2,
+    )
 }
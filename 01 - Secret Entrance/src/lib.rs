@@ -1,34 +1,25 @@
-use std::{num::ParseIntError, str::FromStr};
+use std::{fmt::Display, str::FromStr};
+use toolbox::{LineError, LineReader, ParseError, parse};
 
-#[derive(PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum Rotation {
     Left(u32),
     Right(u32),
 }
 
-#[derive(PartialEq, Debug)]
-pub struct ParseRotationError;
-
-impl From<ParseIntError> for ParseRotationError {
-    fn from(_value: ParseIntError) -> Self {
-        Self
-    }
-}
+const ROTATION: &str = "a rotation like `L68` or `R48`";
 
 impl FromStr for Rotation {
-    type Err = ParseRotationError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let first_letter = s.chars().next().ok_or(ParseRotationError)?;
-
-        let skip_first_letter = &s[1..];
-        let rotate_amount = skip_first_letter.parse()?;
-
-        match first_letter {
-            'L' => Ok(Rotation::Left(rotate_amount)),
-            'R' => Ok(Rotation::Right(rotate_amount)),
-            _ => Err(ParseRotationError),
-        }
+        let mut chars = s.chars();
+        let rotate: fn(u32) -> Rotation = match chars.next() {
+            Some('L') => Rotation::Left,
+            Some('R') => Rotation::Right,
+            _ => return Err(ParseError::expected(ROTATION).with_column(1).with_text(s)),
+        };
+        parse::located(chars.as_str(), 2, str::parse::<u32>).map(rotate)
     }
 }
 
@@ -37,11 +28,35 @@ fn test_we_can_parse_a_rotation() {
     assert_eq!("L68".parse::<Rotation>(), Ok(Rotation::Left(68)));
     assert_eq!("L30".parse::<Rotation>(), Ok(Rotation::Left(30)));
     assert_eq!("R48".parse::<Rotation>(), Ok(Rotation::Right(48)));
-    assert_eq!("Q48".parse::<Rotation>(), Err(ParseRotationError));
-    assert_eq!("".parse::<Rotation>(), Err(ParseRotationError));
-    assert_eq!("L256".parse::<Rotation>(), Ok(Rotation::Left(256)));
     assert_eq!("L256".parse::<Rotation>(), Ok(Rotation::Left(256)));
     assert_eq!("R987".parse::<Rotation>(), Ok(Rotation::Right(987)));
+    assert_eq!(
+        "Q48".parse::<Rotation>().unwrap_err().to_string(),
+        "column 1: expected a rotation like `L68` or `R48`, found \"Q48\""
+    );
+    assert!("".parse::<Rotation>().is_err());
+    assert!("é5".parse::<Rotation>().is_err());
+    assert!("L€".parse::<Rotation>().is_err());
+    assert_eq!(
+        "L4x".parse::<Rotation>().unwrap_err().to_string(),
+        "column 2: expected a number, found \"4x\""
+    );
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rotation::Left(amount) => write!(f, "L{amount}"),
+            Rotation::Right(amount) => write!(f, "R{amount}"),
+        }
+    }
+}
+
+#[test]
+fn test_a_rotation_is_written_as_it_is_read() {
+    for rotation in ["L68", "R0", "R987"] {
+        assert_eq!(rotation.parse::<Rotation>().unwrap().to_string(), rotation);
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -273,6 +288,38 @@ fn test_our_dial_knows_how_often_it_has_hit_zero() {
     );
 }
 
+#[cfg(test)]
+fn rotate_click_by_click(dial_and_count: DialAndCount, rotation: Rotation) -> DialAndCount {
+    let (clicks, step) = match rotation {
+        Rotation::Left(amount) => (amount, Rotation::Left(1)),
+        Rotation::Right(amount) => (amount, Rotation::Right(1)),
+    };
+    (0..clicks).fold(dial_and_count, |DialAndCount { dial, count }, _| {
+        let dial = dial.rotate(step);
+        DialAndCount {
+            dial,
+            count: count + (dial == Dial(0)) as u32,
+        }
+    })
+}
+
+#[test]
+fn test_counting_crossings_agrees_with_turning_click_by_click() {
+    use toolbox::random;
+    random::check(5000, |rng| {
+        let start = DialAndCount {
+            dial: Dial(rng.below(100) as u8),
+            count: 0,
+        };
+        let rotation = random::rotation(rng, 1000).parse::<Rotation>().unwrap();
+        assert_eq!(
+            start.rotate(rotation),
+            rotate_click_by_click(start, rotation),
+            "{start:?} {rotation:?}"
+        );
+    });
+}
+
 impl Default for Dial {
     fn default() -> Self {
         Dial(50)
@@ -284,15 +331,14 @@ fn dial_defaults_to_50() {
     assert_eq!(Dial::default(), Dial(50));
 }
 
-pub fn calculate_password(input: &mut dyn std::io::BufRead) -> u32 {
-    use std::io::BufRead;
-    input
-        .lines()
-        .fold((Dial::default(), 0), |(dial, count), line| {
-            let line = line.expect("failed to read line");
-            let rotation = line.parse::<Rotation>().expect("failed to parse rotation");
+pub fn password(rotations: impl IntoIterator<Item = Rotation>) -> u32 {
+    rotations
+        .into_iter()
+        .fold((Dial::default(), 0), |(dial, count), rotation| {
             let new_dial = dial.rotate(rotation);
+            toolbox::event!(Debug, "rotation", rotation = rotation, dial = new_dial.0);
             if new_dial == Dial(0) {
+                toolbox::event!(Info, "zero", rotation = rotation, count = count + 1);
                 (new_dial, count + 1)
             } else {
                 (new_dial, count)
@@ -301,14 +347,29 @@ pub fn calculate_password(input: &mut dyn std::io::BufRead) -> u32 {
         .1
 }
 
+pub fn calculate_password(input: &mut dyn std::io::BufRead) -> Result<u32, LineError> {
+    LineReader::new(input).stream(str::parse::<Rotation>, |rotations| password(rotations))
+}
+
 #[test]
 fn test_calculate_password() {
     assert_eq!(
         calculate_password(&mut std::io::Cursor::new(
             "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n".as_bytes()
-        )),
+        ))
+        .unwrap(),
         3
     );
+    assert_eq!(
+        calculate_password(&mut std::io::Cursor::new("L68\r\nL30\r\nR48".as_bytes())).unwrap(),
+        1
+    );
+    assert_eq!(
+        calculate_password(&mut std::io::Cursor::new("L68\nL30\nR4x\n".as_bytes()))
+            .unwrap_err()
+            .to_string(),
+        "line 3, column 2: expected a number, found \"4x\""
+    );
 }
 
 #[test]
@@ -322,24 +383,119 @@ fn test_default_dial_and_count_is_dial_50_count_0() {
     );
 }
 
-pub fn calculate_password_2(input: &mut dyn std::io::BufRead) -> u32 {
-    use std::io::BufRead;
-    input
-        .lines()
-        .fold(DialAndCount::default(), |dial_and_count, line| {
-            let line = line.expect("failed to read line");
-            let rotation = line.parse::<Rotation>().expect("failed to parse rotation");
-            dial_and_count.rotate(rotation)
+pub fn password_2(rotations: impl IntoIterator<Item = Rotation>) -> u32 {
+    rotations
+        .into_iter()
+        .fold(DialAndCount::default(), |dial_and_count, rotation| {
+            let rotated = dial_and_count.rotate(rotation);
+            toolbox::event!(
+                Debug,
+                "rotation",
+                rotation = rotation,
+                dial = rotated.dial.0
+            );
+            if rotated.count > dial_and_count.count {
+                toolbox::event!(
+                    Info,
+                    "zero_crossing",
+                    rotation = rotation,
+                    times = rotated.count - dial_and_count.count,
+                    count = rotated.count
+                );
+            }
+            rotated
         })
         .count
 }
 
+#[test]
+fn test_the_dial_logs_each_rotation_and_zero_crossing() {
+    let rotations = ["L68", "L30", "R48"].map(|r| r.parse::<Rotation>().unwrap());
+    let (count, events) = toolbox::trace::capture(1, || password_2(rotations));
+    assert_eq!(count, 2);
+    assert_eq!(
+        events,
+        [
+            "level=info event=zero_crossing rotation=L68 times=1 count=1",
+            "level=info event=zero_crossing rotation=R48 times=1 count=2"
+        ]
+    );
+    let (_, events) = toolbox::trace::capture(2, || password(rotations));
+    assert_eq!(
+        events,
+        [
+            "level=debug event=rotation rotation=L68 dial=82",
+            "level=debug event=rotation rotation=L30 dial=52",
+            "level=debug event=rotation rotation=R48 dial=0",
+            "level=info event=zero rotation=R48 count=1"
+        ]
+    );
+}
+
+pub fn calculate_password_2(input: &mut dyn std::io::BufRead) -> Result<u32, LineError> {
+    LineReader::new(input).stream(str::parse::<Rotation>, |rotations| password_2(rotations))
+}
+
 #[test]
 fn test_calculate_password_2() {
     assert_eq!(
         calculate_password_2(&mut std::io::Cursor::new(
             "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n".as_bytes()
-        )),
+        ))
+        .unwrap(),
         6
     );
 }
+
+pub struct SecretEntrance;
+
+impl toolbox::Solution for SecretEntrance {
+    const DAY: u8 = 1;
+
+    const FUZZ_TARGETS: &'static [toolbox::fuzz::Target] =
+        &[toolbox::fuzz::Target::from_str::<Rotation>("Rotation")];
+
+    type Input = Vec<Rotation>;
+    type Err = toolbox::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        toolbox::parse_lines(input, str::parse::<Rotation>)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(password(input.iter().copied()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(password_2(input.iter().copied()))
+    }
+
+    fn generate(rng: &mut toolbox::random::Rng, size: usize) -> Option<String> {
+        Some(toolbox::random::rotation_list(rng, size))
+    }
+
+    fn summarize(input: &Self::Input) -> Vec<(&'static str, String)> {
+        let dial = input
+            .iter()
+            .fold(Dial::default(), |dial, &rotation| dial.rotate(rotation));
+        vec![
+            ("rotations", input.len().to_string()),
+            ("final position", dial.0.to_string()),
+        ]
+    }
+}
+
+#[test]
+fn test_secret_entrance_solves_both_parts() {
+    use toolbox::Solution;
+    let rotations =
+        SecretEntrance::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n").unwrap();
+    assert_eq!(SecretEntrance::part1(&rotations).unwrap().to_string(), "3");
+    assert_eq!(SecretEntrance::part2(&rotations).unwrap().to_string(), "6");
+    assert_eq!(
+        SecretEntrance::parse("L68\nQ30\n").unwrap_err().to_string(),
+        "line 2, column 1: expected a rotation like `L68` or `R48`, found \"Q30\""
+    );
+}
+
+pub const SOLVERS: &[toolbox::Solver] = &toolbox::solvers::<SecretEntrance>();
@@ -1,8 +1,3 @@
-fn main() {
-    println!(
-        "{}",
-        gift_shop::sum_all_invalid_product_ids_from_input::<gift_shop::LevelOneProductIdValidator>(
-            &mut std::io::stdin().lock()
-        )
-    );
+fn main() -> std::process::ExitCode {
+    toolbox::solution_main::<gift_shop::GiftShop>(1)
 }
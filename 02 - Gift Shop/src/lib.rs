@@ -1,4 +1,5 @@
-use std::{num::ParseIntError, str::FromStr};
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+use toolbox::{IntervalSet, ParseError, parse};
 
 #[test]
 fn we_can_parse_a_single_range() {
@@ -16,35 +17,36 @@ fn we_can_parse_a_single_range() {
         "95-115".parse::<ProductIdRange>(),
         Ok(ProductIdRange(95, 115))
     );
-    assert_eq!("".parse::<ProductIdRange>(), Err(ParseProductIdRangeError));
-    assert_eq!("-".parse::<ProductIdRange>(), Err(ParseProductIdRangeError));
+    assert!("".parse::<ProductIdRange>().is_err());
+    assert!("-".parse::<ProductIdRange>().is_err());
+    assert!("11-".parse::<ProductIdRange>().is_err());
+    assert!("-22".parse::<ProductIdRange>().is_err());
     assert_eq!(
-        "11-".parse::<ProductIdRange>(),
-        Err(ParseProductIdRangeError)
-    );
-    assert_eq!(
-        "-22".parse::<ProductIdRange>(),
-        Err(ParseProductIdRangeError)
+        "22-11".parse::<ProductIdRange>(),
+        Ok(ProductIdRange(22, 11))
     );
 }
 
 #[derive(Debug, PartialEq)]
 pub struct ProductIdRange(u64, u64);
 
-#[derive(Debug, PartialEq)]
-pub struct ParseProductIdRangeError;
-
-impl From<ParseIntError> for ParseProductIdRangeError {
-    fn from(_value: ParseIntError) -> Self {
-        Self
+impl From<&ProductIdRange> for RangeInclusive<u64> {
+    fn from(&ProductIdRange(start, end): &ProductIdRange) -> Self {
+        start..=end
     }
 }
 
 impl FromStr for ProductIdRange {
-    type Err = ParseProductIdRangeError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s.split_once("-").ok_or(ParseProductIdRangeError)?;
-        Ok(ProductIdRange(start.parse()?, end.parse()?))
+        let (start, end) = parse::pair(
+            s,
+            "-",
+            "a product id range like `11-22`",
+            str::parse,
+            str::parse,
+        )?;
+        Ok(ProductIdRange(start, end))
     }
 }
 
@@ -80,11 +82,8 @@ fn we_can_parse_a_comma_separated_list_of_product_id_ranges() {
 
 pub fn parse_comma_separated_list_of_product_id_ranges(
     input: &str,
-) -> Result<Vec<ProductIdRange>, ParseProductIdRangeError> {
-    input
-        .split_terminator(',')
-        .map(ProductIdRange::from_str)
-        .collect()
+) -> Result<Vec<ProductIdRange>, ParseError> {
+    parse::separated(input, ',', ProductIdRange::from_str)
 }
 
 #[test]
@@ -160,6 +159,18 @@ fn can_sum_all_level_one_invalid_product_ids_from_buf_read() {
         ),
         33
     );
+    assert_eq!(
+        sum_all_invalid_product_ids_from_input::<LevelOneProductIdValidator>(
+            &mut std::io::Cursor::new("11-22,11-22".as_bytes())
+        ),
+        66
+    );
+    assert_eq!(
+        sum_all_invalid_product_ids_from_input::<LevelOneProductIdValidator>(
+            &mut std::io::Cursor::new("22-11".as_bytes())
+        ),
+        0
+    );
 
     assert_eq!(sum_all_invalid_product_ids_from_input::<LevelOneProductIdValidator>(&mut std::io::Cursor::new("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124"
         .as_bytes())), 1227775554);
@@ -172,10 +183,11 @@ pub fn sum_all_invalid_product_ids_from_input<V: ProductIdValidator>(
     input.read_to_string(&mut all_input).unwrap();
     let ranges = parse_comma_separated_list_of_product_id_ranges(all_input.trim())
         .expect("failed to parse product id ranges");
-    ranges
-        .iter()
-        .map(ProductIdRange::sum_invalid_product_ids::<V>)
-        .sum()
+    sum_all_invalid_product_ids::<V>(&ranges)
+}
+
+pub fn sum_all_invalid_product_ids<V: ProductIdValidator>(ranges: &[ProductIdRange]) -> u64 {
+    toolbox::parallel::sum(ranges, ProductIdRange::sum_invalid_product_ids::<V>)
 }
 
 pub struct LevelTwoProductIdValidator;
@@ -252,3 +264,62 @@ fn can_sum_all_level_two_invalid_product_ids_from_buf_read() {
     assert_eq!(sum_all_invalid_product_ids_from_input::<LevelTwoProductIdValidator>(&mut std::io::Cursor::new("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124"
         .as_bytes())), 4174379265);
 }
+
+pub struct GiftShop;
+
+impl toolbox::Solution for GiftShop {
+    const DAY: u8 = 2;
+
+    const FUZZ_TARGETS: &'static [toolbox::fuzz::Target] = &[toolbox::fuzz::Target::from_str::<
+        ProductIdRange,
+    >("ProductIdRange")];
+
+    type Input = Vec<ProductIdRange>;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        parse_comma_separated_list_of_product_id_ranges(input.trim_end())
+            .map_err(|e| e.with_line(1))
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(sum_all_invalid_product_ids::<LevelOneProductIdValidator>(
+            input,
+        ))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(sum_all_invalid_product_ids::<LevelTwoProductIdValidator>(
+            input,
+        ))
+    }
+
+    fn generate(rng: &mut toolbox::random::Rng, size: usize) -> Option<String> {
+        Some(toolbox::random::product_id_ranges(rng, size))
+    }
+
+    fn summarize(input: &Self::Input) -> Vec<(&'static str, String)> {
+        let input = input
+            .iter()
+            .map(RangeInclusive::from)
+            .collect::<IntervalSet<_>>();
+        vec![
+            ("merged ranges", input.ranges().count().to_string()),
+            ("product ids", input.len().to_string()),
+        ]
+    }
+}
+
+#[test]
+fn test_gift_shop_solves_both_parts() {
+    use toolbox::Solution;
+    let ranges = GiftShop::parse("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124\n").unwrap();
+    assert_eq!(GiftShop::part1(&ranges).unwrap().to_string(), "1227775554");
+    assert_eq!(GiftShop::part2(&ranges).unwrap().to_string(), "4174379265");
+    assert_eq!(
+        GiftShop::parse("11-22,95").unwrap_err().to_string(),
+        "line 1, column 7: expected a product id range like `11-22`, found \"95\""
+    );
+}
+
+pub const SOLVERS: &[toolbox::Solver] = &toolbox::solvers::<GiftShop>();
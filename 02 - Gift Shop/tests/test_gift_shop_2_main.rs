@@ -1,26 +1,14 @@
-const EXECUTABLE_UNDER_TEST: &str = toolbox::binary_path!("gift_shop_2");
-
-const TEST_CASES: &[(&str, &str)] = &[
-    ("", "0\n"),
-    (
-        "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124",
-        "4174379265\n",
-    ),
-    (
-        "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124\n",
-        "4174379265\n",
-    ),
-];
-
-#[test]
-fn test_that_the_executable_exists() {
-    assert!(std::fs::exists(EXECUTABLE_UNDER_TEST).unwrap());
-}
-
-#[test]
-fn test_that_the_executable_processes_the_input_and_produces_the_output() {
-    for &(input, expected_result) in TEST_CASES {
-        let child_output = toolbox::invoke_executable(EXECUTABLE_UNDER_TEST, input);
-        assert_eq!(child_output, expected_result);
-    }
-}
+toolbox::executable_tests!(
+    "gift_shop_2",
+    &[
+        ("", "0\n"),
+        (
+            "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124",
+            "4174379265\n",
+        ),
+        (
+            "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124\n",
+            "4174379265\n",
+        ),
+    ]
+);
@@ -7,14 +7,14 @@ fn bench_max_joltage(c: &mut Criterion) {
     for connection_count in 1..=12 {
         g.throughput(criterion::Throughput::Elements(connection_count as u64));
         g.bench_with_input(
-            criterion::BenchmarkId::new("max_joltage", &connection_count),
+            criterion::BenchmarkId::new("max_joltage", connection_count),
             &connection_count,
             |b, &connection_count| {
                 b.iter(|| lobby::max_joltage(black_box(bank), black_box(connection_count)))
             },
         );
         g.bench_with_input(
-            criterion::BenchmarkId::new("max_joltage_dp", &connection_count),
+            criterion::BenchmarkId::new("max_joltage_dp", connection_count),
             &connection_count,
             |b, &connection_count| {
                 b.iter(|| lobby::max_joltage_dp(black_box(bank), black_box(connection_count)))
@@ -1,6 +1,3 @@
-fn main() {
-    println!(
-        "{}",
-        lobby::calculate_total_joltage(&mut std::io::stdin().lock(), 2)
-    );
+fn main() -> std::process::ExitCode {
+    toolbox::streaming_main::<lobby::Lobby, _>(1, |input| lobby::calculate_total_joltage(input, 2))
 }
@@ -1,6 +1,5 @@
-fn main() {
-    println!(
-        "{}",
-        lobby::calculate_total_joltage(&mut std::io::stdin().lock(), 12)
-    );
+fn main() -> std::process::ExitCode {
+    toolbox::streaming_main::<lobby::Lobby, _>(2, |input| {
+        lobby::calculate_total_joltage(input, lobby::MOST_CONNECTIONS)
+    })
 }
@@ -1,37 +1,33 @@
-pub fn max_joltage(bank: &str, connection_count: usize) -> u64 {
-    let digits = max_joltage_digits(
-        &bank
-            .chars()
-            .map(|c| c.to_digit(10).unwrap() as u64)
-            .collect::<Vec<_>>(),
-        connection_count,
-    );
+use std::fmt::Display;
+use toolbox::memo::Memo;
 
-    let mut joltage: u64 = 0;
-    for digit in digits.iter() {
-        joltage = joltage * 10 + digit;
-    }
-    joltage
+fn joltages(bank: &str) -> Vec<u64> {
+    bank.chars()
+        .map(|c| c.to_digit(10).unwrap() as u64)
+        .collect()
 }
 
-fn max_joltage_digits(digits: &[u64], connection_count: usize) -> Vec<u64> {
+pub fn max_joltage(bank: &str, connection_count: usize) -> u64 {
+    max_joltage_from(&joltages(bank), connection_count, &mut Memo::new())
+}
+
+fn max_joltage_from(
+    digits: &[u64],
+    connection_count: usize,
+    memo: &mut Memo<(usize, usize), u64>,
+) -> u64 {
+    if connection_count == 0 {
+        return 0;
+    }
     if digits.len() == connection_count {
-        digits.to_vec()
-    } else if digits.len() == connection_count + 1 {
-        // figure out what the largest number is when dropping one digit
-        (0..digits.len())
-            .map(|i| {
-                let mut tmp = digits.to_vec();
-                tmp.remove(i);
-                tmp
-            })
-            .max()
-            .unwrap()
-    } else {
-        let mut subcase = max_joltage_digits(&digits[1..], connection_count);
-        subcase.insert(0, digits[0]);
-        max_joltage_digits(&subcase, connection_count)
+        return digits.iter().fold(0, |joltage, digit| joltage * 10 + digit);
     }
+    memo.get_or_insert_with((digits.len(), connection_count), |memo| {
+        let taken = digits[0] * 10_u64.pow(connection_count as u32 - 1)
+            + max_joltage_from(&digits[1..], connection_count - 1, memo);
+        let skipped = max_joltage_from(&digits[1..], connection_count, memo);
+        taken.max(skipped)
+    })
 }
 
 #[test]
@@ -54,21 +50,57 @@ fn we_can_find_the_max_joltage_of_a_bank() {
     assert_eq!(max_joltage("818181911112111", 12), 888911112111);
 }
 
-pub fn calculate_total_joltage(input: &mut dyn std::io::BufRead, battery_count: usize) -> u64 {
-    use std::io::BufRead;
-    input
-        .lines()
-        .map(|line| max_joltage(&line.unwrap(), battery_count))
-        .sum()
+#[test]
+fn test_the_joltage_search_reuses_subproblems() {
+    let mut memo = Memo::new();
+    assert_eq!(
+        max_joltage_from(&joltages("987654321111111"), 12, &mut memo),
+        987654321111
+    );
+    assert!(memo.stats().hits > 0);
+}
+
+const BANKS_PER_BATCH: usize = 1024;
+
+pub fn calculate_total_joltage(
+    input: &mut dyn std::io::BufRead,
+    battery_count: usize,
+) -> Result<u64, toolbox::LineError> {
+    total_joltage_in_batches(input, battery_count, BANKS_PER_BATCH)
+}
+
+fn total_joltage_in_batches(
+    input: &mut dyn std::io::BufRead,
+    battery_count: usize,
+    banks_per_batch: usize,
+) -> Result<u64, toolbox::LineError> {
+    toolbox::LineReader::new(input).stream(
+        |bank| {
+            let bank = parse_bank(bank)?;
+            check_connections(&bank, battery_count)?;
+            Ok::<_, toolbox::ParseError>(bank)
+        },
+        |banks| {
+            let mut total = 0;
+            loop {
+                let batch = banks.take(banks_per_batch).collect::<Vec<String>>();
+                if batch.is_empty() {
+                    return total;
+                }
+                total += toolbox::parallel::sum(&batch, |bank| max_joltage(bank, battery_count));
+            }
+        },
+    )
 }
 #[test]
 fn we_can_calculate_total_joltage_of_a_series_of_banks() {
     assert_eq!(
-        calculate_total_joltage(&mut std::io::Cursor::new("".as_bytes()), 2),
+        calculate_total_joltage(&mut std::io::Cursor::new("".as_bytes()), 2).unwrap(),
         0
     );
     assert_eq!(
-        calculate_total_joltage(&mut std::io::Cursor::new("987654321111111\n".as_bytes()), 2),
+        calculate_total_joltage(&mut std::io::Cursor::new("987654321111111\n".as_bytes()), 2)
+            .unwrap(),
         98
     );
     assert_eq!(
@@ -77,7 +109,8 @@ fn we_can_calculate_total_joltage_of_a_series_of_banks() {
                 "987654321111111\n811111111111119\n234234234234278\n818181911112111\n".as_bytes()
             ),
             2
-        ),
+        )
+        .unwrap(),
         357
     );
     assert_eq!(
@@ -86,19 +119,51 @@ fn we_can_calculate_total_joltage_of_a_series_of_banks() {
                 "987654321111111\n811111111111119\n234234234234278\n818181911112111\n".as_bytes()
             ),
             12
-        ),
+        )
+        .unwrap(),
         3121910778619
     );
+    assert_eq!(
+        calculate_total_joltage(&mut std::io::Cursor::new("987\r\n811\r\n".as_bytes()), 2).unwrap(),
+        98 + 81
+    );
+    assert_eq!(
+        calculate_total_joltage(&mut std::io::Cursor::new("987\n8\n".as_bytes()), 2)
+            .unwrap_err()
+            .to_string(),
+        "line 2: expected a bank of at least 2 batteries, found \"8\""
+    );
+}
+
+#[test]
+fn test_streamed_banks_add_up_across_batches() {
+    use toolbox::random;
+    random::check(300, |rng| {
+        let connection_count = 1 + rng.below(4);
+        let text = (0..rng.below(30))
+            .map(|_| {
+                let len = connection_count + rng.below(10);
+                random::bank(rng, len) + "\n"
+            })
+            .collect::<String>();
+        let banks = parse_banks(&text).unwrap();
+        let banks_per_batch = 1 + rng.below(8);
+        toolbox::parallel::with_threads(1 + rng.below(4), || {
+            assert_eq!(
+                total_joltage_in_batches(&mut text.as_bytes(), connection_count, banks_per_batch)
+                    .unwrap(),
+                total_joltage(&banks, connection_count).unwrap(),
+                "{banks_per_batch} banks per batch:\n{text}"
+            );
+        });
+    });
 }
 // jolts[0] = [  9   8   7   6   5   4   3   2   1   1   1   1   1   1   1 ]
 // jolts[1] = [ 98  87  76  65  54  43  32  21  11  11  11  11  11  11   . ]
 // jolts[2] = [987 876 765 654 543 432 321 211 111 111 111 111 111   .   . ]
 
 pub fn max_joltage_dp(bank: &str, connection_count: usize) -> u64 {
-    let mut base = bank
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u64)
-        .collect::<Vec<_>>();
+    let mut base = joltages(bank);
     let mut table = base.clone();
     for c in 1..connection_count {
         for i in 0..table.len() - c {
@@ -137,3 +202,188 @@ fn we_can_find_the_max_joltage_of_a_bank_with_dp() {
     assert_eq!(max_joltage_dp("234234234234278", 12), 434234234278);
     assert_eq!(max_joltage_dp("818181911112111", 12), 888911112111);
 }
+
+#[cfg(test)]
+fn max_joltage_brute_force(bank: &str, connection_count: usize) -> u64 {
+    let digits = bank.bytes().map(|b| (b - b'0') as u64).collect::<Vec<_>>();
+    (0u32..1 << digits.len())
+        .filter(|chosen| chosen.count_ones() as usize == connection_count)
+        .map(|chosen| {
+            (0..digits.len())
+                .filter(|i| chosen & 1 << i != 0)
+                .fold(0, |joltage, i| joltage * 10 + digits[i])
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn the_joltage_finders_agree_with_trying_every_choice() {
+    use toolbox::random;
+    random::check(2000, |rng| {
+        let len = 1 + rng.below(12);
+        let bank = random::bank(rng, len);
+        let connection_count = 1 + rng.below(bank.len());
+        let expected = max_joltage_brute_force(&bank, connection_count);
+        assert_eq!(max_joltage(&bank, connection_count), expected, "{bank}");
+        assert_eq!(max_joltage_dp(&bank, connection_count), expected, "{bank}");
+    });
+}
+
+#[test]
+fn the_joltage_finders_agree_on_full_size_banks() {
+    use toolbox::random;
+    random::check(1000, |rng| {
+        let len = 12 + rng.below(89);
+        let bank = random::bank(rng, len);
+        for connection_count in [2, 12] {
+            assert_eq!(
+                max_joltage(&bank, connection_count),
+                max_joltage_dp(&bank, connection_count),
+                "{bank}"
+            );
+        }
+    });
+}
+
+fn parse_bank(line: &str) -> Result<String, toolbox::ParseError> {
+    match line.chars().position(|c| !c.is_ascii_digit()) {
+        Some(index) => {
+            Err(toolbox::ParseError::expected("a battery joltage digit").with_column(index + 1))
+        }
+        None => Ok(line.to_string()),
+    }
+}
+
+pub fn parse_banks(input: &str) -> Result<Vec<String>, toolbox::ParseError> {
+    toolbox::parse_lines(input, parse_bank)
+}
+
+#[test]
+fn we_can_parse_a_series_of_banks() {
+    assert_eq!(parse_banks(""), Ok(vec![]));
+    assert_eq!(
+        parse_banks("987654321111111\n811111111111119\n"),
+        Ok(vec![
+            "987654321111111".to_string(),
+            "811111111111119".to_string()
+        ])
+    );
+    assert_eq!(
+        parse_banks("987654321111\n98765432111x\n"),
+        Err(toolbox::ParseError {
+            line: Some(2),
+            column: Some(12),
+            text: Some("98765432111x".to_string()),
+            expected: "a battery joltage digit".to_string()
+        })
+    );
+    assert_eq!(
+        parse_banks("987654321\n"),
+        Ok(vec!["987654321".to_string()])
+    );
+}
+
+// How short a bank may be depends on the part, so it is checked when solving rather than parsing.
+fn check_connections(bank: &str, connection_count: usize) -> Result<(), toolbox::ParseError> {
+    if bank.len() < connection_count {
+        return Err(toolbox::ParseError::expected(format!(
+            "a bank of at least {connection_count} batteries"
+        )));
+    }
+    Ok(())
+}
+
+pub const MOST_CONNECTIONS: usize = 12;
+
+pub fn total_joltage(
+    banks: &[String],
+    connection_count: usize,
+) -> Result<u64, toolbox::ParseError> {
+    for (index, bank) in banks.iter().enumerate() {
+        check_connections(bank, connection_count)
+            .map_err(|e| e.with_line(index + 1).with_text(bank))?;
+    }
+    Ok(toolbox::parallel::sum(banks, |bank| {
+        max_joltage(bank, connection_count)
+    }))
+}
+
+#[test]
+fn test_each_part_needs_banks_of_its_own_length() {
+    let banks = parse_banks("987654321\n").unwrap();
+    assert_eq!(total_joltage(&banks, 2), Ok(98));
+    assert_eq!(
+        total_joltage(&banks, MOST_CONNECTIONS)
+            .unwrap_err()
+            .to_string(),
+        "line 1: expected a bank of at least 12 batteries, found \"987654321\""
+    );
+}
+
+pub struct Lobby;
+
+impl toolbox::Solution for Lobby {
+    const DAY: u8 = 3;
+
+    const FUZZ_TARGETS: &'static [toolbox::fuzz::Target] = &[
+        toolbox::fuzz::Target {
+            name: "parse_bank",
+            parse: |input| {
+                let _ = parse_bank(input);
+            },
+        },
+        toolbox::fuzz::Target {
+            name: "max_joltage",
+            parse: |input| {
+                let Ok(bank) = parse_bank(input) else {
+                    return;
+                };
+                for connection_count in [2, MOST_CONNECTIONS] {
+                    if check_connections(&bank, connection_count).is_ok() {
+                        max_joltage(&bank, connection_count);
+                    }
+                }
+            },
+        },
+    ];
+
+    type Input = Vec<String>;
+    type Err = toolbox::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        parse_banks(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        total_joltage(input, 2)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        total_joltage(input, MOST_CONNECTIONS)
+    }
+
+    fn generate(rng: &mut toolbox::random::Rng, size: usize) -> Option<String> {
+        Some(toolbox::random::banks(rng, size))
+    }
+
+    fn summarize(input: &Self::Input) -> Vec<(&'static str, String)> {
+        let batteries = input.iter().map(String::len).sum::<usize>();
+        vec![
+            ("banks", input.len().to_string()),
+            ("batteries", batteries.to_string()),
+        ]
+    }
+}
+
+#[test]
+fn test_lobby_solves_both_parts() {
+    use toolbox::Solution;
+    let banks =
+        Lobby::parse("987654321111111\n811111111111119\n234234234234278\n818181911112111\n")
+            .unwrap();
+    assert_eq!(Lobby::part1(&banks).unwrap().to_string(), "357");
+    assert_eq!(Lobby::part2(&banks).unwrap().to_string(), "3121910778619");
+}
+
+pub const SOLVERS: &[toolbox::Solver] = &toolbox::solvers::<Lobby>();
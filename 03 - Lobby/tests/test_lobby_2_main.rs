@@ -1,23 +1,11 @@
-const EXECUTABLE_UNDER_TEST: &str = toolbox::binary_path!("lobby_2");
-
-const TEST_CASES: &[(&str, &str)] = &[
-    ("", "0\n"),
-    ("987654321111111\n", "987654321111\n"),
-    (
-        "987654321111111\n811111111111119\n234234234234278\n818181911112111\n",
-        "3121910778619\n",
-    ),
-];
-
-#[test]
-fn test_that_the_executable_exists() {
-    assert!(std::fs::exists(EXECUTABLE_UNDER_TEST).unwrap());
-}
-
-#[test]
-fn test_that_the_executable_processes_the_input_and_produces_the_output() {
-    for &(input, expected_result) in TEST_CASES {
-        let child_output = toolbox::invoke_executable(EXECUTABLE_UNDER_TEST, input);
-        assert_eq!(child_output, expected_result);
-    }
-}
+toolbox::executable_tests!(
+    "lobby_2",
+    &[
+        ("", "0\n"),
+        ("987654321111111\n", "987654321111\n"),
+        (
+            "987654321111111\n811111111111119\n234234234234278\n818181911112111\n",
+            "3121910778619\n",
+        ),
+    ]
+);
@@ -1,8 +1,12 @@
 use criterion::{Criterion, criterion_group, criterion_main};
 use std::hint::black_box;
+use toolbox::Solution;
+use toolbox::input_store::InputStore;
 
 fn bench_subtract_rolls_until_complete(c: &mut Criterion) {
-    let input = std::fs::read_to_string("input.txt").expect("failed to read from file");
+    let input = InputStore::locate()
+        .read(printing_department::PrintingDepartment::DAY, None)
+        .unwrap_or_else(|e| panic!("{e}"));
     let rolls = input
         .parse::<printing_department::CellSet>()
         .expect("failed to parse input");
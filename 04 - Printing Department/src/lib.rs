@@ -1,181 +1,95 @@
-use hashbrown::HashSet;
+use std::fmt::Display;
 use std::str::FromStr;
+use toolbox::Grid;
 
-#[derive(Copy, Clone, PartialEq, Debug, Hash, Eq)]
-pub struct Cell {
-    pub row: i32,
-    pub col: i32,
-}
+pub type Cell = toolbox::grid::Position;
 
-impl Cell {
-    pub fn adjacent_cells(&self) -> [Cell; 8] {
-        [
-            Cell {
-                row: self.row + -1,
-                col: self.col,
-            },
-            Cell {
-                row: self.row + -1,
-                col: self.col + 1,
-            },
-            Cell {
-                row: self.row,
-                col: self.col + 1,
-            },
-            Cell {
-                row: self.row + 1,
-                col: self.col + 1,
-            },
-            Cell {
-                row: self.row + 1,
-                col: self.col,
-            },
-            Cell {
-                row: self.row + 1,
-                col: self.col + -1,
-            },
-            Cell {
-                row: self.row,
-                col: self.col + -1,
-            },
-            Cell {
-                row: self.row + -1,
-                col: self.col + -1,
-            },
-        ]
-    }
+#[derive(Clone, Default, Debug)]
+pub struct CellSet {
+    rolls: Grid<bool>,
 }
-#[test]
-fn test_a_cell_can_get_its_adjacent_cells() {
-    assert_eq!(Cell { row: 0, col: 0 }.adjacent_cells().len(), 8);
-    assert!(
-        [
-            Cell { row: -1, col: 0 },
-            Cell { row: -1, col: 1 },
-            Cell { row: 0, col: 1 },
-            Cell { row: 1, col: 1 },
-            Cell { row: 1, col: 0 },
-            Cell { row: 1, col: -1 },
-            Cell { row: 0, col: -1 },
-            Cell { row: -1, col: -1 },
-        ]
-        .into_iter()
-        .all(|c| Cell { row: 0, col: 0 }.adjacent_cells().contains(&c))
-    );
 
-    assert!(
-        [
-            Cell { row: 9, col: 10 },
-            Cell { row: 9, col: 11 },
-            Cell { row: 10, col: 11 },
-            Cell { row: 11, col: 11 },
-            Cell { row: 11, col: 10 },
-            Cell { row: 11, col: 9 },
-            Cell { row: 10, col: 9 },
-            Cell { row: 9, col: 9 },
-        ]
-        .into_iter()
-        .all(|c| Cell { row: 10, col: 10 }.adjacent_cells().contains(&c))
-    );
+impl CellSet {
+    fn cells(&self) -> impl Iterator<Item = Cell> {
+        self.rolls
+            .iter()
+            .filter(|(_, roll)| **roll)
+            .map(|(cell, _)| cell)
+    }
 
-    assert!(
-        [
-            Cell { row: 2, col: 5 },
-            Cell { row: 2, col: 6 },
-            Cell { row: 3, col: 6 },
-            Cell { row: 4, col: 6 },
-            Cell { row: 4, col: 5 },
-            Cell { row: 4, col: 4 },
-            Cell { row: 3, col: 4 },
-            Cell { row: 2, col: 4 },
-        ]
-        .into_iter()
-        .all(|c| Cell { row: 3, col: 5 }.adjacent_cells().contains(&c))
-    );
-}
+    pub fn len(&self) -> usize {
+        self.cells().count()
+    }
 
-#[derive(Clone, Default, PartialEq, Debug)]
-pub struct CellSet {
-    cells: HashSet<Cell>,
-}
+    pub fn is_empty(&self) -> bool {
+        self.cells().next().is_none()
+    }
 
-impl CellSet {
     pub fn count_occupied_adjacent_cells(&self, cell: Cell) -> usize {
-        cell.adjacent_cells()
-            .into_iter()
-            .collect::<CellSet>()
-            .cells
-            .intersection(&self.cells)
+        self.rolls
+            .neighbours_8(cell)
+            .filter(|&neighbour| self.rolls[neighbour])
             .count()
     }
 
     pub fn count_accessible_rolls(&self) -> usize {
-        self.cells
-            .iter()
-            .filter(|cell| self.count_occupied_adjacent_cells(**cell) < 4)
-            .count()
+        self.accessible_rolls().count()
     }
+
     pub fn accessible_rolls(&self) -> impl Iterator<Item = Cell> {
-        self.cells
-            .iter()
-            .copied()
+        self.cells()
             .filter(|cell| self.count_occupied_adjacent_cells(*cell) < 4)
     }
 
     pub fn subtract_rolls(&self, rolls: impl IntoIterator<Item = Cell>) -> CellSet {
-        CellSet {
-            cells: self
-                .cells
-                .difference(&rolls.into_iter().collect())
-                .copied()
-                .collect(),
+        let mut remaining = self.clone();
+        for cell in rolls {
+            if let Some(roll) = remaining.rolls.get_mut(cell) {
+                *roll = false;
+            }
         }
+        remaining
+    }
+}
+
+impl PartialEq for CellSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells().eq(other.cells())
     }
 }
 
 impl FromIterator<Cell> for CellSet {
     fn from_iter<I: IntoIterator<Item = Cell>>(iter: I) -> Self {
-        let mut set = CellSet::default();
-
-        for c in iter {
-            set.cells.insert(c);
+        let cells = iter.into_iter().collect::<Vec<_>>();
+        let height = cells.iter().map(|cell| cell.row + 1).max().unwrap_or(0);
+        let width = cells.iter().map(|cell| cell.col + 1).max().unwrap_or(0);
+        let mut rolls = Grid::new(width, height, false);
+        for cell in cells {
+            rolls[cell] = true;
         }
-
-        set
+        CellSet { rolls }
     }
 }
 
-#[derive(Debug, PartialEq)]
-pub struct ParseCellSetError;
-
 impl FromStr for CellSet {
-    type Err = ParseCellSetError;
+    type Err = toolbox::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.lines()
-            .enumerate()
-            .flat_map(|(row, line)| {
-                line.chars()
-                    .enumerate()
-                    .filter_map(move |(col, c)| match c {
-                        '.' => None,
-                        '@' => Some(Ok(Cell {
-                            row: row as i32,
-                            col: col as i32,
-                        })),
-                        _ => Some(Err(ParseCellSetError)),
-                    })
-            })
-            .collect()
+        let rolls = Grid::parse_sparse(s, false, |c| match c {
+            '.' => Ok(false),
+            '@' => Ok(true),
+            _ => Err(toolbox::ParseError::expected("`.` or `@`")),
+        })?;
+        Ok(CellSet { rolls })
     }
 }
 
 impl IntoIterator for CellSet {
-    type IntoIter = hashbrown::hash_set::IntoIter<Cell>;
+    type IntoIter = std::vec::IntoIter<Cell>;
     type Item = Cell;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.cells.into_iter()
+        self.cells().collect::<Vec<_>>().into_iter()
     }
 }
 
@@ -205,19 +119,26 @@ fn test_the_cell_set_can_count_how_many_adjacent_cells_are_occupied() {
         0
     );
     assert_eq!(
-        [Cell { row: -1, col: 0 }]
+        [Cell { row: 0, col: 1 }]
             .into_iter()
             .collect::<CellSet>()
-            .count_occupied_adjacent_cells(Cell { row: 0, col: 0 }),
+            .count_occupied_adjacent_cells(Cell { row: 1, col: 1 }),
         1
     );
     assert_eq!(
-        [Cell { row: -2, col: 0 }]
+        [Cell { row: 0, col: 1 }]
             .into_iter()
             .collect::<CellSet>()
-            .count_occupied_adjacent_cells(Cell { row: 0, col: 0 }),
+            .count_occupied_adjacent_cells(Cell { row: 2, col: 1 }),
         0
     );
+    assert_eq!(
+        "@@@\n@@@\n@@@\n"
+            .parse::<CellSet>()
+            .unwrap()
+            .count_occupied_adjacent_cells(Cell { row: 0, col: 0 }),
+        3
+    );
 }
 
 #[test]
@@ -228,11 +149,31 @@ fn test_we_can_translate_text_into_a_cell_set() {
         "@".parse::<CellSet>(),
         Ok([Cell { row: 0, col: 0 }].into_iter().collect::<CellSet>())
     );
-    assert_eq!("X".parse::<CellSet>(), Err(ParseCellSetError));
+    assert_eq!(
+        "X".parse::<CellSet>().unwrap_err().to_string(),
+        "line 1, column 1: expected `.` or `@`, found \"X\""
+    );
     assert_eq!(
         ".@".parse::<CellSet>(),
         Ok([Cell { row: 0, col: 1 }].into_iter().collect::<CellSet>())
     );
+    assert_eq!(
+        "@@\n@@\n\n"
+            .parse::<CellSet>()
+            .unwrap()
+            .count_accessible_rolls(),
+        4
+    );
+    assert_eq!(
+        "@\n.@@\n".parse::<CellSet>(),
+        Ok([
+            Cell { row: 0, col: 0 },
+            Cell { row: 1, col: 1 },
+            Cell { row: 1, col: 2 },
+        ]
+        .into_iter()
+        .collect::<CellSet>())
+    );
     assert_eq!(
         "..@@.@@@@.".parse::<CellSet>(),
         Ok([
@@ -356,24 +297,35 @@ fn test_cell_set_can_subtract_rolls() {
     );
 }
 
-pub fn subtract_rolls_until_complete(mut rolls: CellSet) -> usize {
-    let mut total_removed: usize = 0;
+pub fn removal_waves(mut rolls: CellSet) -> Vec<usize> {
+    let mut waves = vec![];
 
     loop {
         let accessible_rolls = rolls.accessible_rolls().collect::<CellSet>();
 
-        let count_before = rolls.cells.len();
+        let count_before = rolls.len();
         rolls = rolls.subtract_rolls(accessible_rolls);
-        let count_after = rolls.cells.len();
+        let count_after = rolls.len();
 
         let removed_count = count_before.abs_diff(count_after);
         if removed_count == 0 {
             break;
         }
-        total_removed += removed_count;
+        waves.push(removed_count);
+        toolbox::event!(
+            Info,
+            "removal_wave",
+            wave = waves.len(),
+            removed = removed_count,
+            remaining = count_after
+        );
     }
 
-    total_removed
+    waves
+}
+
+pub fn subtract_rolls_until_complete(rolls: CellSet) -> usize {
+    removal_waves(rolls).iter().sum()
 }
 
 #[test]
@@ -384,3 +336,76 @@ fn test_subtract_rolls_until_complete() {
         6
     );
 }
+
+#[test]
+fn test_rolls_are_removed_in_waves() {
+    assert_eq!(removal_waves(CellSet::default()), Vec::<usize>::new());
+    assert_eq!(
+        removal_waves("..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.".parse::<CellSet>().unwrap()),
+        [13, 12, 7, 5, 2, 1, 1, 1, 1]
+    );
+    let (_, events) = toolbox::trace::capture(1, || {
+        removal_waves("@@@\n@@@\n".parse::<CellSet>().unwrap())
+    });
+    assert_eq!(
+        events,
+        [
+            "level=info event=removal_wave wave=1 removed=4 remaining=2",
+            "level=info event=removal_wave wave=2 removed=2 remaining=0"
+        ]
+    );
+}
+
+pub struct PrintingDepartment;
+
+impl toolbox::Solution for PrintingDepartment {
+    const DAY: u8 = 4;
+
+    const FUZZ_TARGETS: &'static [toolbox::fuzz::Target] =
+        &[toolbox::fuzz::Target::from_str::<CellSet>("CellSet")];
+
+    type Input = CellSet;
+    type Err = toolbox::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(input.count_accessible_rolls())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(subtract_rolls_until_complete(input.clone()))
+    }
+
+    fn generate(rng: &mut toolbox::random::Rng, size: usize) -> Option<String> {
+        Some(toolbox::random::roll_grid(rng, size))
+    }
+
+    fn summarize(input: &Self::Input) -> Vec<(&'static str, String)> {
+        vec![
+            ("rolls", input.len().to_string()),
+            (
+                "removal waves",
+                removal_waves(input.clone()).len().to_string(),
+            ),
+        ]
+    }
+}
+
+#[test]
+fn test_printing_department_solves_both_parts() {
+    use toolbox::Solution;
+    let rolls = PrintingDepartment::parse("..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.").unwrap();
+    assert_eq!(PrintingDepartment::part1(&rolls).unwrap().to_string(), "13");
+    assert_eq!(PrintingDepartment::part2(&rolls).unwrap().to_string(), "43");
+    assert_eq!(
+        PrintingDepartment::parse("..@\n.x@\n")
+            .unwrap_err()
+            .to_string(),
+        "line 2, column 2: expected `.` or `@`, found \".x@\""
+    );
+}
+
+pub const SOLVERS: &[toolbox::Solver] = &toolbox::solvers::<PrintingDepartment>();
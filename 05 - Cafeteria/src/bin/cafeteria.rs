@@ -1,10 +1,3 @@
-fn main() {
-    println!(
-        "{}",
-        toolbox::read_to_string(&mut std::io::stdin().lock())
-            .unwrap()
-            .parse::<cafeteria::Input>()
-            .unwrap()
-            .count_fresh_products()
-    );
+fn main() -> std::process::ExitCode {
+    toolbox::solution_main::<cafeteria::Cafeteria>(1)
 }
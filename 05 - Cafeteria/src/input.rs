@@ -1,7 +1,6 @@
-use crate::product_id_list::ParseProductIdListError;
-use crate::product_id_range_list::ParseProductIdRangeListError;
 use crate::{ProductCount, ProductIdList, ProductIdRangeList};
 use std::str::FromStr;
+use toolbox::{ParseError, parse};
 
 #[derive(Debug, Default, PartialEq)]
 pub struct Input {
@@ -10,45 +9,49 @@ pub struct Input {
 }
 
 impl Input {
-    pub fn count_fresh_products(self) -> ProductCount {
+    pub fn new(
+        fresh_product_ranges: ProductIdRangeList,
+        available_products: ProductIdList,
+    ) -> Self {
+        Self {
+            fresh_product_ranges,
+            available_products,
+        }
+    }
+
+    pub fn count_fresh_products(&self) -> ProductCount {
         ProductCount(
             self.available_products
-                .into_iter()
+                .iter()
                 .filter(|&product_id| self.fresh_product_ranges.contains(product_id))
-                .count() as u64,
+                .count() as u128,
         )
     }
 
     pub fn count_total_fresh_product_ids(&self) -> ProductCount {
         self.fresh_product_ranges.total_count()
     }
-}
 
-#[derive(Debug, PartialEq)]
-pub struct ParseInputError;
-
-impl From<ParseProductIdRangeListError> for ParseInputError {
-    fn from(_: ParseProductIdRangeListError) -> Self {
-        Self
+    pub fn count_fresh_product_ranges(&self) -> usize {
+        self.fresh_product_ranges.range_count()
     }
-}
 
-impl From<ParseProductIdListError> for ParseInputError {
-    fn from(_: ParseProductIdListError) -> Self {
-        Self
+    pub fn count_available_products(&self) -> usize {
+        self.available_products.iter().count()
     }
 }
 
 impl FromStr for Input {
-    type Err = ParseInputError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (fresh_product_ranges, available_products) =
-            s.split_once("\n\n").ok_or(ParseInputError)?;
-        Ok(Self {
-            fresh_product_ranges: fresh_product_ranges.parse()?,
-            available_products: available_products.parse()?,
-        })
+        let (fresh_product_ranges, available_products) = parse::two_sections(
+            s,
+            "fresh product id ranges, a blank line, then available product ids",
+            ProductIdRangeList::from_str,
+            ProductIdList::from_str,
+        )?;
+        Ok(Self::new(fresh_product_ranges, available_products))
     }
 }
 
@@ -77,12 +80,16 @@ fn we_can_read_our_input_from_a_buf_read() {
         })
     );
     assert_eq!(
-        Input::from_str("3\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n"),
-        Err(ParseInputError)
+        Input::from_str("3\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n")
+            .unwrap_err()
+            .line,
+        Some(1)
     );
     assert_eq!(
-        Input::from_str("3-5\n10-14\n16-20\n12-18\n\n1\nQ\n8\n11\n17\n32\n"),
-        Err(ParseInputError)
+        Input::from_str("3-5\n10-14\n16-20\n12-18\n\n1\nQ\n8\n11\n17\n32\n")
+            .unwrap_err()
+            .line,
+        Some(7)
     );
 }
 
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 mod input;
 mod product_count;
 mod product_id;
@@ -6,9 +8,73 @@ mod product_id_range;
 mod product_id_range_list;
 
 pub use input::Input;
-pub use input::ParseInputError;
 pub use product_count::ProductCount;
 pub use product_id::ProductId;
 pub use product_id_list::ProductIdList;
 pub use product_id_range::ProductIdRange;
 pub use product_id_range_list::ProductIdRangeList;
+
+pub struct Cafeteria;
+
+impl toolbox::Solution for Cafeteria {
+    const DAY: u8 = 5;
+
+    const FUZZ_TARGETS: &'static [toolbox::fuzz::Target] = &[
+        toolbox::fuzz::Target::from_str::<ProductIdRange>("ProductIdRange"),
+        toolbox::fuzz::Target::from_str::<ProductId>("ProductId"),
+    ];
+
+    type Input = Input;
+    type Err = toolbox::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(input.count_fresh_products())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(input.count_total_fresh_product_ids())
+    }
+
+    fn generate(rng: &mut toolbox::random::Rng, size: usize) -> Option<String> {
+        Some(toolbox::random::cafeteria(rng, size))
+    }
+
+    fn summarize(input: &Self::Input) -> Vec<(&'static str, String)> {
+        vec![
+            (
+                "merged ranges",
+                input.count_fresh_product_ranges().to_string(),
+            ),
+            (
+                "available products",
+                input.count_available_products().to_string(),
+            ),
+        ]
+    }
+}
+
+#[test]
+fn test_cafeteria_solves_both_parts() {
+    use toolbox::Solution;
+    let input = Cafeteria::parse("3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n").unwrap();
+    assert_eq!(Cafeteria::part1(&input).unwrap().to_string(), "3");
+    assert_eq!(Cafeteria::part2(&input).unwrap().to_string(), "14");
+    assert_eq!(
+        Cafeteria::parse("3-5\n").unwrap_err().to_string(),
+        "expected fresh product id ranges, a blank line, then available product ids"
+    );
+    assert_eq!(
+        Cafeteria::parse("3-5\n5-3\n\n1\n").unwrap_err().to_string(),
+        "line 2: expected a product id range like `3-5`, found \"5-3\""
+    );
+    assert_eq!(
+        Cafeteria::parse("3-5\n\n1\nQ\n").unwrap_err().to_string(),
+        "line 4: expected a number, found \"Q\""
+    );
+}
+
+pub const SOLVERS: &[toolbox::Solver] = &toolbox::solvers::<Cafeteria>();
@@ -2,7 +2,7 @@ use std::fmt::Display;
 use std::ops::Add;
 
 #[derive(Debug, PartialEq, Default)]
-pub struct ProductCount(pub u64);
+pub struct ProductCount(pub u128);
 
 impl Add<Self> for ProductCount {
     type Output = Self;
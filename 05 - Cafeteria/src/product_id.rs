@@ -1,13 +1,28 @@
 use crate::ProductCount;
 use std::num::ParseIntError;
 use std::str::FromStr;
+use toolbox::Step;
 
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Eq)]
 pub struct ProductId(pub u64);
 
 impl ProductId {
     pub fn count_product_ids_up_to_and_including(self, product: ProductId) -> ProductCount {
-        ProductCount(self.0.abs_diff(product.0) + 1)
+        ProductCount(u128::from(self.0.abs_diff(product.0)) + 1)
+    }
+}
+
+impl Step for ProductId {
+    fn successor(self) -> Option<Self> {
+        self.0.successor().map(ProductId)
+    }
+
+    fn predecessor(self) -> Option<Self> {
+        self.0.predecessor().map(ProductId)
+    }
+
+    fn count_inclusive(start: Self, end: Self) -> u128 {
+        u64::count_inclusive(start.0, end.0)
     }
 }
 
@@ -1,28 +1,23 @@
 use crate::ProductId;
 use std::str::FromStr;
+use toolbox::ParseError;
 
 #[derive(PartialEq, Debug, Default)]
 pub struct ProductIdList {
     product_ids: Vec<ProductId>,
 }
 
-#[derive(Debug, PartialEq)]
-pub struct ParseProductIdListError;
-
-impl From<std::num::ParseIntError> for ParseProductIdListError {
-    fn from(_: std::num::ParseIntError) -> Self {
-        Self
+impl ProductIdList {
+    pub fn iter(&self) -> impl Iterator<Item = ProductId> + '_ {
+        self.product_ids.iter().copied()
     }
 }
 
 impl FromStr for ProductIdList {
-    type Err = ParseProductIdListError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            product_ids: s
-                .lines()
-                .map(<ProductId as FromStr>::from_str)
-                .collect::<Result<_, _>>()?,
+            product_ids: toolbox::parse_lines(s, ProductId::from_str)?,
         })
     }
 }
@@ -62,6 +57,10 @@ fn test_we_can_parse_a_product_id_list_from_a_str() {
             ProductId(32),
         ]))
     );
+    assert_eq!(
+        "1\nQ\n".parse::<ProductIdList>().unwrap_err().to_string(),
+        "line 2: expected a number, found \"Q\""
+    );
 }
 
 #[test]
@@ -96,3 +95,13 @@ fn we_can_iterate_product_ids_from_a_product_id_list() {
         ]
     );
 }
+
+#[test]
+fn we_can_iterate_product_ids_from_a_borrowed_product_id_list() {
+    let list = "1\n5\n8\n".parse::<ProductIdList>().unwrap();
+    assert_eq!(
+        list.iter().collect::<Vec<_>>(),
+        vec![ProductId(1), ProductId(5), ProductId(8)]
+    );
+    assert_eq!(list.iter().count(), 3);
+}
@@ -1,8 +1,8 @@
 use crate::ProductCount;
 use crate::ProductId;
-use std::num::ParseIntError;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
+use toolbox::{ParseError, parse};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ProductIdRange(RangeInclusive<ProductId>);
@@ -21,144 +21,19 @@ impl ProductIdRange {
             .start()
             .count_product_ids_up_to_and_including(*self.0.end())
     }
-
-    pub fn overlaps(&self, other: &ProductIdRange) -> bool {
-        self.0.end() >= other.0.start() && self.0.start() <= other.0.end()
-    }
-
-    pub fn merge(&self, other: &Self) -> Self {
-        let new_start = std::cmp::min(*self.0.start(), *other.0.start());
-        let new_end = std::cmp::max(*self.0.end(), *other.0.end());
-
-        Self(new_start..=new_end)
-    }
-}
-
-#[test]
-fn we_can_detect_overlapping_product_id_ranges() {
-    assert!(
-        "3-5"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .overlaps(&"4-7".parse::<ProductIdRange>().unwrap())
-    );
-    assert!(
-        !"3-5"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .overlaps(&"7-9".parse::<ProductIdRange>().unwrap())
-    );
-    assert!(
-        !"7-9"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .overlaps(&"3-5".parse::<ProductIdRange>().unwrap())
-    );
-    assert!(
-        "3-5"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .overlaps(&"5-7".parse::<ProductIdRange>().unwrap())
-    );
-    assert!(
-        "3-5"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .overlaps(&"1-3".parse::<ProductIdRange>().unwrap())
-    );
-    assert!(
-        "3-5"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .overlaps(&"1-4".parse::<ProductIdRange>().unwrap())
-    );
-    assert!(
-        "1-5"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .overlaps(&"2-4".parse::<ProductIdRange>().unwrap())
-    );
-    assert!(
-        "2-4"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .overlaps(&"1-5".parse::<ProductIdRange>().unwrap())
-    );
 }
 
-#[test]
-fn we_can_merge_two_product_id_ranges() {
-    assert_eq!(
-        "0-0"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .merge(&"0-0".parse::<ProductIdRange>().unwrap()),
-        "0-0".parse::<ProductIdRange>().unwrap()
-    );
-    assert_eq!(
-        "0-0"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .merge(&"0-1".parse::<ProductIdRange>().unwrap()),
-        "0-1".parse::<ProductIdRange>().unwrap()
-    );
-    assert_eq!(
-        "0-1"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .merge(&"0-0".parse::<ProductIdRange>().unwrap()),
-        "0-1".parse::<ProductIdRange>().unwrap()
-    );
-    assert_eq!(
-        "0-1"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .merge(&"1-1".parse::<ProductIdRange>().unwrap()),
-        "0-1".parse::<ProductIdRange>().unwrap()
-    );
-    assert_eq!(
-        "10-14"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .merge(&"12-18".parse::<ProductIdRange>().unwrap()),
-        "10-18".parse::<ProductIdRange>().unwrap()
-    );
-    assert_eq!(
-        "16-20"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .merge(&"12-18".parse::<ProductIdRange>().unwrap()),
-        "12-20".parse::<ProductIdRange>().unwrap()
-    );
-    assert_eq!(
-        "12-18"
-            .parse::<ProductIdRange>()
-            .unwrap()
-            .merge(&"16-20".parse::<ProductIdRange>().unwrap()),
-        "12-20".parse::<ProductIdRange>().unwrap()
-    );
-}
-
-#[derive(Debug, PartialEq)]
-pub struct ParseProductIdRangeError;
-
-impl From<ParseIntError> for ParseProductIdRangeError {
-    fn from(_: ParseIntError) -> ParseProductIdRangeError {
-        Self
+impl From<ProductIdRange> for RangeInclusive<ProductId> {
+    fn from(range: ProductIdRange) -> Self {
+        range.0
     }
 }
 
 impl FromStr for ProductIdRange {
-    type Err = ParseProductIdRangeError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s.split_once('-').ok_or(ParseProductIdRangeError)?;
-        let start = start.parse()?;
-        let end = end.parse()?;
-        if start > end {
-            return Err(ParseProductIdRangeError);
-        }
-        Ok(ProductIdRange::new(start, end))
+        parse::range(s, "a product id range like `3-5`").map(ProductIdRange)
     }
 }
 
@@ -180,27 +55,15 @@ fn test_can_parse_product_id_range_from_str() {
         "12-18".parse::<ProductIdRange>(),
         Ok(ProductIdRange::new(ProductId(12), ProductId(18)))
     );
-    assert_eq!("".parse::<ProductIdRange>(), Err(ParseProductIdRangeError));
-    assert_eq!(
-        "12".parse::<ProductIdRange>(),
-        Err(ParseProductIdRangeError)
-    );
-    assert_eq!("-".parse::<ProductIdRange>(), Err(ParseProductIdRangeError));
-    assert_eq!(
-        "12-".parse::<ProductIdRange>(),
-        Err(ParseProductIdRangeError)
-    );
-    assert_eq!(
-        "-12".parse::<ProductIdRange>(),
-        Err(ParseProductIdRangeError)
-    );
-    assert_eq!(
-        "12--12".parse::<ProductIdRange>(),
-        Err(ParseProductIdRangeError)
-    );
+    for malformed in ["", "12", "-", "12-", "-12", "12--12"] {
+        assert!(
+            malformed.parse::<ProductIdRange>().is_err(),
+            "{malformed:?}"
+        );
+    }
     assert_eq!(
-        "2-1".parse::<ProductIdRange>(),
-        Err(ParseProductIdRangeError)
+        "2-1".parse::<ProductIdRange>().unwrap_err().to_string(),
+        "expected a product id range like `3-5`, found \"2-1\""
     );
 }
 
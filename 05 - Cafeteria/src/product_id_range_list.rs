@@ -1,45 +1,41 @@
 use crate::ProductIdRange;
-use crate::product_id_range::ParseProductIdRangeError;
 use crate::{ProductCount, ProductId};
 use std::str::FromStr;
+use toolbox::{IntervalSet, ParseError, Step};
 
 #[derive(Debug, PartialEq, Default)]
 pub struct ProductIdRangeList {
-    ranges: Vec<ProductIdRange>,
+    ranges: IntervalSet<ProductId>,
 }
 
 impl ProductIdRangeList {
     pub fn contains(&self, id: ProductId) -> bool {
-        self.ranges.iter().any(|r| r.contains(id))
+        self.ranges.contains(id)
+    }
+
+    pub fn range_count(&self) -> usize {
+        self.ranges.ranges().count()
     }
 
     pub fn total_count(&self) -> ProductCount {
-        self.ranges
-            .iter()
-            .fold(ProductCount::default(), |partial_sum, range| {
-                partial_sum + range.count()
-            })
+        for range in self.ranges.ranges() {
+            toolbox::event!(
+                Info,
+                "merged_range",
+                start = range.start().0,
+                end = range.end().0,
+                ids = ProductId::count_inclusive(*range.start(), *range.end())
+            );
+        }
+        ProductCount(self.ranges.len())
     }
 }
 
 impl FromIterator<ProductIdRange> for ProductIdRangeList {
     fn from_iter<T: IntoIterator<Item = ProductIdRange>>(iter: T) -> Self {
-        let mut ranges: Vec<ProductIdRange> = vec![];
-
-        for new_product_id_range in iter {
-            let (overlapping, mut non_overlapping) = ranges
-                .iter()
-                .cloned()
-                .partition::<Vec<_>, _>(|r| r.overlaps(&new_product_id_range));
-            let combined = overlapping
-                .iter()
-                .fold(new_product_id_range, |r1, r2| r1.merge(r2));
-
-            non_overlapping.push(combined);
-            ranges = non_overlapping;
+        Self {
+            ranges: iter.into_iter().map(Into::into).collect(),
         }
-
-        Self { ranges }
     }
 }
 
@@ -62,24 +58,43 @@ fn a_product_id_range_list_can_count_how_many_ids_are_in_its_ranges() {
             .total_count(),
         ProductCount(14)
     );
+    assert_eq!(
+        ProductIdRangeList::from_str("0-18446744073709551615")
+            .unwrap()
+            .total_count(),
+        ProductCount(18446744073709551616)
+    );
 }
 
-#[derive(Debug, PartialEq)]
-pub struct ParseProductIdRangeListError;
-
-impl From<ParseProductIdRangeError> for ParseProductIdRangeListError {
-    fn from(_: ParseProductIdRangeError) -> Self {
-        Self
-    }
+#[test]
+fn a_product_id_range_list_merges_overlapping_ranges() {
+    assert_eq!(ProductIdRangeList::default().range_count(), 0);
+    assert_eq!(
+        ProductIdRangeList::from_str("3-5\n10-14\n16-20\n12-18")
+            .unwrap()
+            .range_count(),
+        2
+    );
+    let (_, events) = toolbox::trace::capture(1, || {
+        ProductIdRangeList::from_str("3-5\n10-14\n16-20\n12-18")
+            .unwrap()
+            .total_count()
+    });
+    assert_eq!(
+        events,
+        [
+            "level=info event=merged_range start=3 end=5 ids=3",
+            "level=info event=merged_range start=10 end=20 ids=11"
+        ]
+    );
 }
 
 impl FromStr for ProductIdRangeList {
-    type Err = ParseProductIdRangeListError;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.lines()
-            .map(<ProductIdRange>::from_str)
-            .map(|res| res.map_err(|_| ParseProductIdRangeListError))
-            .collect::<Result<Self, _>>()
+        Ok(toolbox::parse_lines(s, ProductIdRange::from_str)?
+            .into_iter()
+            .collect())
     }
 }
 
@@ -90,8 +105,11 @@ fn test_parse_a_product_id_range_list_from_a_string() {
         Ok(ProductIdRangeList::default())
     );
     assert_eq!(
-        "-".parse::<ProductIdRangeList>(),
-        Err(ParseProductIdRangeListError)
+        "3-5\n-"
+            .parse::<ProductIdRangeList>()
+            .unwrap_err()
+            .to_string(),
+        "line 2, column 1: expected a number, found \"\""
     );
     assert_eq!(
         "3-5".parse::<ProductIdRangeList>(),
@@ -1,4 +1,6 @@
+use std::fmt::Display;
 use std::str::FromStr;
+use toolbox::{Grid, ParseError, parse};
 
 pub const SAMPLE_INPUT: &str = "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +  ";
 
@@ -9,12 +11,12 @@ pub enum Operator {
 }
 
 impl FromStr for Operator {
-    type Err = anyhow::Error;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "+" => Ok(Operator::Sum),
             "*" => Ok(Operator::Product),
-            _ => anyhow::bail!("Unknown operator: {}", s),
+            _ => Err(ParseError::expected("an operator, `+` or `*`").with_text(s)),
         }
     }
 }
@@ -37,63 +39,39 @@ pub struct Homework {
     problems: Vec<Problem>,
 }
 
-pub trait Transpose: Sized {
-    fn transpose(self) -> Self;
-}
-
-impl<ElementType: Clone> Transpose for Vec<Vec<ElementType>> {
-    fn transpose(self) -> Vec<Vec<ElementType>> {
-        let inner_input_len = self.iter().map(|row| row.len()).max().unwrap_or_default();
-        let mut result = vec![];
-        for i in 0..inner_input_len {
-            let mut tmp = vec![];
-            for row in &self {
-                tmp.push(row[i].clone());
-            }
-            result.push(tmp);
-        }
-        result
-    }
-}
-
-#[test]
-fn test_2d_array_transposition() {
-    assert_eq!(Vec::<Vec<char>>::new().transpose(), Vec::<Vec<char>>::new());
-    assert_eq!(vec![vec![1, 2]].transpose(), vec![vec![1], vec![2]]);
-    assert_eq!(vec![vec![1], vec![2]].transpose(), vec![vec![1, 2]]);
-    assert_eq!(
-        vec![vec![1, 2], vec![3, 4]].transpose(),
-        vec![vec![1, 3], vec![2, 4]]
-    );
-    assert_eq!(
-        vec![vec![1, 2], vec![3, 4]].transpose(),
-        vec![vec![1, 3], vec![2, 4]]
-    );
+fn parse_operators(line: &str, line_number: usize) -> Result<Vec<Operator>, ParseError> {
+    parse::columns(line, Operator::from_str).map_err(|e| e.with_line(line_number))
 }
 
 impl Homework {
-    pub fn parse_v1(s: &str) -> anyhow::Result<Self> {
+    pub fn parse_v1(s: &str) -> Result<Self, ParseError> {
         let mut lines = s.lines();
-        let last_line = lines.next_back().unwrap();
-        let operators: Vec<Operator> = last_line
-            .split(' ')
-            .filter(|col| !col.is_empty())
-            .map(|col| col.parse::<Operator>())
-            .collect::<Result<Vec<Operator>, _>>()?;
+        let last_line = lines
+            .next_back()
+            .ok_or_else(|| ParseError::expected("a line of operators"))?;
+        let operator_line = s.lines().count();
+        let operators = parse_operators(last_line, operator_line)?;
+        let rows = lines
+            .enumerate()
+            .map(|(index, l)| {
+                parse::columns(l, str::parse::<u64>).map_err(|e| e.with_line(index + 1))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let grid = Grid::from_rows(rows)?;
+        if operators.len() != grid.width() {
+            return Err(ParseError::expected(format!(
+                "one operator per column, {} in all",
+                grid.width()
+            ))
+            .with_line(operator_line)
+            .with_text(last_line));
+        }
         Ok(Self {
-            problems: lines
-                .map(|l| {
-                    l.split(' ')
-                        .filter(|col| !col.is_empty())
-                        .map(|col| col.parse())
-                        .collect::<Result<Vec<_>, _>>()
-                })
-                .collect::<Result<Vec<Vec<u64>>, _>>()?
-                .transpose()
-                .into_iter()
+            problems: grid
+                .columns()
                 .zip(operators)
                 .map(|(col, op)| Problem {
-                    operands: col,
+                    operands: col.copied().collect(),
                     operator: op,
                 })
                 .collect(),
@@ -254,7 +232,7 @@ fn problem_can_give_us_its_solution() {
 
 impl Homework {
     pub fn sum_of_problems(&self) -> u64 {
-        self.problems.iter().map(|p| p.solve()).sum()
+        toolbox::parallel::sum(&self.problems, Problem::solve)
     }
 }
 
@@ -287,14 +265,13 @@ fn we_can_do_our_homework() {
 }
 
 impl Homework {
-    pub fn parse_v2(s: &str) -> anyhow::Result<Self> {
+    pub fn parse_v2(s: &str) -> Result<Self, ParseError> {
         let mut lines = s.lines();
-        let operator_line = lines.next_back().unwrap();
+        let operator_line = lines
+            .next_back()
+            .ok_or_else(|| ParseError::expected("a line of operators"))?;
 
-        let operators = operator_line
-            .split(' ')
-            .filter(|col| !col.is_empty())
-            .map(|col| col.parse::<Operator>().unwrap());
+        let operators = parse_operators(operator_line, s.lines().count())?;
 
         let mut operand_rows = lines
             .map(|l| l.chars().collect::<Vec<char>>())
@@ -309,22 +286,31 @@ impl Homework {
             operand_row.resize_with(max_len, || ' ');
         }
 
-        let operand_cols = operand_rows.transpose();
+        let operand_grid = Grid::from_rows(operand_rows)?;
 
-        let operand_strings_all_together = operand_cols
-            .into_iter()
-            .map(|c| c.into_iter().collect::<String>())
-            .collect::<Vec<String>>();
+        let operand_strings_all_together = operand_grid
+            .columns()
+            .map(|c| c.collect::<String>())
+            .enumerate()
+            .collect::<Vec<(usize, String)>>();
         let problems = operand_strings_all_together
-            .split(|x| x.trim().is_empty())
+            .split(|(_, x)| x.trim().is_empty())
             .map(|x| {
                 x.iter()
-                    .map(|o| o.trim().parse().unwrap())
+                    .map(|(index, o)| {
+                        o.trim().parse().map_err(|e| {
+                            ParseError::from(e)
+                                .with_column(index + 1)
+                                .with_text(o.trim())
+                        })
+                    })
                     .rev()
-                    .collect::<Vec<u64>>()
+                    .collect::<Result<Vec<u64>, _>>()
             })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
             .rev()
-            .zip(operators.rev())
+            .zip(operators.into_iter().rev())
             .map(|(operands, operator)| Problem { operands, operator })
             .collect::<Vec<_>>();
 
@@ -412,3 +398,114 @@ fn we_can_parse_an_input_v2() {
         }
     );
 }
+
+#[test]
+fn parse_errors_point_at_the_offending_field() {
+    assert_eq!(
+        Homework::parse_v1("1 2\n3 x\n+ *").unwrap_err().to_string(),
+        "line 2, column 3: expected a number, found \"x\""
+    );
+    assert_eq!(
+        Homework::parse_v1("1 2\n+ /").unwrap_err().to_string(),
+        "line 2, column 3: expected an operator, `+` or `*`, found \"/\""
+    );
+    assert_eq!(
+        Homework::parse_v2("1 2\n3 x\n+ *").unwrap_err().to_string(),
+        "column 3: expected a number, found \"2x\""
+    );
+    assert_eq!(
+        Homework::parse_v1("1 2\n3\n+ *").unwrap_err().to_string(),
+        "line 2: expected a row of 2 cells"
+    );
+    assert_eq!(
+        Homework::parse_v1("1 2\n3 4\n+").unwrap_err().to_string(),
+        "line 3: expected one operator per column, 2 in all, found \"+\""
+    );
+    assert_eq!(
+        Homework::parse_v1("1\n3\n+ * +").unwrap_err().to_string(),
+        "line 3: expected one operator per column, 1 in all, found \"+ * +\""
+    );
+    assert!(Homework::parse_v1("").is_err());
+    assert!(Homework::parse_v2("").is_err());
+}
+
+pub struct TrashCompactor;
+
+impl toolbox::Solution for TrashCompactor {
+    const DAY: u8 = 6;
+
+    const FUZZ_TARGETS: &'static [toolbox::fuzz::Target] = &[
+        toolbox::fuzz::Target::from_str::<Operator>("Operator"),
+        toolbox::fuzz::Target {
+            name: "Homework::parse_v1",
+            parse: |input| {
+                let _ = Homework::parse_v1(input);
+            },
+        },
+        toolbox::fuzz::Target {
+            name: "Homework::parse_v2",
+            parse: |input| {
+                let _ = Homework::parse_v2(input);
+            },
+        },
+    ];
+
+    type Input = String;
+    type Err = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(Homework::parse_v1(input)?.sum_of_problems())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(Homework::parse_v2(input)?.sum_of_problems())
+    }
+
+    fn generate(rng: &mut toolbox::random::Rng, size: usize) -> Option<String> {
+        Some(toolbox::random::homework(rng, size))
+    }
+
+    fn summarize(input: &Self::Input) -> Vec<(&'static str, String)> {
+        let homework = match Homework::parse_v1(input) {
+            Ok(homework) => homework,
+            Err(e) => return vec![("problems", e.to_string())],
+        };
+        let operands = homework
+            .problems
+            .iter()
+            .map(|problem| problem.operands.len())
+            .sum::<usize>();
+        vec![
+            ("problems", homework.problems.len().to_string()),
+            ("operands", operands.to_string()),
+        ]
+    }
+}
+
+#[test]
+fn test_trash_compactor_solves_both_parts() {
+    use toolbox::Solution;
+    let homework = TrashCompactor::parse(SAMPLE_INPUT).unwrap();
+    assert_eq!(
+        TrashCompactor::part1(&homework).unwrap().to_string(),
+        "4277556"
+    );
+    assert_eq!(
+        TrashCompactor::part2(&homework).unwrap().to_string(),
+        "3263827"
+    );
+}
+
+#[test]
+fn test_each_part_reads_the_worksheet_its_own_way() {
+    use toolbox::Solution;
+    let worksheet = TrashCompactor::parse("12 3\n 4  \n*  +").unwrap();
+    assert!(TrashCompactor::part1(&worksheet).is_err());
+    assert_eq!(TrashCompactor::part2(&worksheet).unwrap().to_string(), "27");
+}
+
+pub const SOLVERS: &[toolbox::Solver] = &toolbox::solvers::<TrashCompactor>();
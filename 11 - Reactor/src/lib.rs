@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::fmt::Display;
 use std::str::FromStr;
 
 pub const SAMPLE_INPUT: &str = r#"aaa: you hhh
@@ -31,14 +31,20 @@ pub struct Node(pub String);
 
 #[derive(Debug, Default, PartialEq)]
 pub struct Graph {
-    adjacencies: HashMap<Node, Vec<Node>>,
+    graph: toolbox::Graph<String>,
 }
 
 impl<const N: usize> From<[(Node, Vec<Node>); N]> for Graph {
     fn from(adjacencies: [(Node, Vec<Node>); N]) -> Self {
-        Graph {
-            adjacencies: HashMap::from(adjacencies),
+        let mut graph = toolbox::Graph::new();
+        for (Node(from), to_list) in adjacencies {
+            let from = graph.intern(from);
+            for Node(to) in to_list {
+                let to = graph.intern(to);
+                graph.add_edge(from, to);
+            }
         }
+        Graph { graph }
     }
 }
 #[test]
@@ -46,7 +52,7 @@ fn test_the_default_graph_has_no_nodes_or_edges() {
     assert_eq!(
         Graph::default(),
         Graph {
-            adjacencies: HashMap::new()
+            graph: toolbox::Graph::new()
         }
     );
 }
@@ -58,37 +64,31 @@ fn test_we_can_construct_a_graph_from_an_array_of_tuples() {
 }
 
 impl Graph {
-    fn cached_count_paths_from_and_to(
+    pub fn count_paths_from_and_to(
         &self,
         from: Node,
         to: Node,
-        cache: &mut HashMap<Node, usize>,
-    ) -> usize {
-        if let Some(cached_count) = cache.get(&from) {
-            return *cached_count;
+    ) -> Result<usize, toolbox::ParseError> {
+        if from == to {
+            return Ok(1);
+        }
+        match (self.graph.id(&from.0), self.graph.id(&to.0)) {
+            (Some(from), Some(to)) => self
+                .graph
+                .count_paths(from, to)
+                .map_err(|cycle| self.feedback_loop(cycle)),
+            _ => Ok(0),
         }
-
-        let count = if from == to {
-            1
-        } else {
-            self.adjacencies
-                .get(&from)
-                .map(|adj| {
-                    adj.clone()
-                        .into_iter()
-                        .map(|i| self.cached_count_paths_from_and_to(i, to.clone(), cache))
-                        .sum()
-                })
-                .unwrap_or_default()
-        };
-
-        cache.insert(from, count);
-        count
     }
 
-    pub fn count_paths_from_and_to(&self, from: Node, to: Node) -> usize {
-        let mut cache: HashMap<Node, usize> = HashMap::new();
-        self.cached_count_paths_from_and_to(from, to, &mut cache)
+    fn feedback_loop(&self, cycle: toolbox::graph::Cycle) -> toolbox::ParseError {
+        let devices = cycle
+            .0
+            .into_iter()
+            .map(|id| self.graph.name(id).as_str())
+            .collect::<Vec<_>>();
+        toolbox::ParseError::expected("devices without feedback loops")
+            .with_text(&devices.join(" -> "))
     }
 }
 #[test]
@@ -96,7 +96,7 @@ fn test_there_is_exactly_one_path_from_an_existing_node_to_itself() {
     let node = Node("out".to_string());
     assert_eq!(
         Graph::from([(node.clone(), vec![])]).count_paths_from_and_to(node.clone(), node.clone()),
-        1
+        Ok(1)
     )
 }
 
@@ -107,7 +107,7 @@ fn test_are_no_paths_between_unconnected_nodes() {
     assert_eq!(
         Graph::from([(from.clone(), vec![]), (to.clone(), vec![]),])
             .count_paths_from_and_to(from.clone(), to.clone()),
-        0
+        Ok(0)
     )
 }
 #[test]
@@ -117,7 +117,7 @@ fn test_is_exactly_one_path_in_a_binodal_graph_from_one_node_to_its_directed_nei
     assert_eq!(
         Graph::from([(from.clone(), vec![to.clone()]), (to.clone(), vec![]),])
             .count_paths_from_and_to(from.clone(), to.clone()),
-        1
+        Ok(1)
     )
 }
 
@@ -133,26 +133,18 @@ fn test_a_connection_to_an_irrelevant_node_does_not_a_path_make() {
             (irrelevant.clone(), vec![]),
         ])
         .count_paths_from_and_to(from.clone(), to.clone()),
-        0
+        Ok(0)
     )
 }
 
 impl FromStr for Graph {
-    type Err = ();
+    type Err = toolbox::ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            adjacencies: s
-                .lines()
-                .map(|line| -> Result<(Node, Vec<Node>), ()> {
-                    let (from, rest) = line.split_once(':').ok_or(())?;
-                    let to_list = rest
-                        .split(' ')
-                        .filter(|s| !s.is_empty())
-                        .map(|s| Node(s.to_string()))
-                        .collect::<Vec<Node>>();
-                    Ok((Node(from.to_string()), to_list))
-                })
-                .collect::<Result<HashMap<Node, Vec<Node>>, ()>>()?,
+            graph: toolbox::Graph::parse_adjacency_list(
+                s,
+                "a device and its outputs, like `aaa: bbb ccc`",
+            )?,
         })
     }
 }
@@ -190,7 +182,7 @@ fn parse_a_graph() {
 }
 
 impl Graph {
-    pub fn count_paths_including(&self, nodes: Vec<Node>) -> usize {
+    pub fn count_paths_including(&self, nodes: Vec<Node>) -> Result<usize, toolbox::ParseError> {
         nodes
             .windows(2)
             .map(|pair| self.count_paths_from_and_to(pair[0].clone(), pair[1].clone()))
@@ -210,13 +202,13 @@ fn we_can_count_paths_following_a_list_of_nodes() {
 
     assert_eq!(
         sample_graph().count_paths_including(vec![you.clone(), out.clone()]),
-        5
+        Ok(5)
     );
 
     assert_eq!(
         Graph::from([(you.clone(), vec![out.clone()])])
             .count_paths_including(vec![you.clone(), out.clone()]),
-        1
+        Ok(1)
     );
 
     assert_eq!(
@@ -226,7 +218,7 @@ fn we_can_count_paths_following_a_list_of_nodes() {
             (bbb.clone(), vec![out.clone()]),
         ])
         .count_paths_including(vec![you.clone(), aaa.clone(), out.clone()]),
-        1
+        Ok(1)
     );
 
     assert_eq!(
@@ -234,13 +226,115 @@ fn we_can_count_paths_following_a_list_of_nodes() {
             .parse::<Graph>()
             .unwrap()
             .count_paths_including(vec![svr.clone(), fft.clone(), dac.clone(), out.clone()]),
-        2
+        Ok(2)
     );
     assert_eq!(
         SAMPLE_INPUT2
             .parse::<Graph>()
             .unwrap()
             .count_paths_including(vec![svr.clone(), dac.clone(), fft.clone(), out.clone()]),
-        0
+        Ok(0)
+    );
+}
+
+pub struct Reactor;
+
+impl toolbox::Solution for Reactor {
+    const DAY: u8 = 11;
+
+    const FUZZ_TARGETS: &'static [toolbox::fuzz::Target] =
+        &[toolbox::fuzz::Target::from_str::<Graph>("Graph")];
+
+    type Input = Graph;
+    type Err = toolbox::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        input.count_paths_from_and_to(Node("you".to_string()), Node("out".to_string()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(input.count_paths_including(vec![
+            Node("svr".to_string()),
+            Node("fft".to_string()),
+            Node("dac".to_string()),
+            Node("out".to_string()),
+        ])? + input.count_paths_including(vec![
+            Node("svr".to_string()),
+            Node("dac".to_string()),
+            Node("fft".to_string()),
+            Node("out".to_string()),
+        ])?)
+    }
+
+    fn generate(rng: &mut toolbox::random::Rng, size: usize) -> Option<String> {
+        Some(toolbox::random::reactor(rng, size))
+    }
+
+    fn summarize(input: &Self::Input) -> Vec<(&'static str, String)> {
+        let paths = input
+            .count_paths_from_and_to(Node("svr".to_string()), Node("out".to_string()))
+            .map_or_else(|e| e.to_string(), |paths| paths.to_string());
+        vec![
+            ("devices", input.graph.len().to_string()),
+            ("paths from svr to out", paths),
+        ]
+    }
+}
+
+#[test]
+fn test_reactor_solves_both_parts() {
+    use toolbox::Solution;
+    assert_eq!(
+        Reactor::part1(&Reactor::parse(SAMPLE_INPUT).unwrap())
+            .unwrap()
+            .to_string(),
+        "5"
+    );
+    assert_eq!(
+        Reactor::part2(&Reactor::parse(SAMPLE_INPUT2).unwrap())
+            .unwrap()
+            .to_string(),
+        "2"
+    );
+    assert_eq!(
+        Reactor::parse("aaa: bbb\nbbb out\n")
+            .unwrap_err()
+            .to_string(),
+        "line 2: expected a device and its outputs, like `aaa: bbb ccc`, found \"bbb out\""
+    );
+    let looped = Reactor::parse("you: aaa\naaa: bbb\nbbb: ccc\nccc: aaa out\nsvr: out\n").unwrap();
+    assert_eq!(
+        Reactor::part1(&looped)
+            .map(|paths| paths.to_string())
+            .unwrap_err()
+            .to_string(),
+        "expected devices without feedback loops, found \"aaa -> bbb -> ccc\""
+    );
+    assert_eq!(Reactor::part2(&looped).unwrap().to_string(), "0");
+}
+
+pub const SOLVERS: &[toolbox::Solver] = &toolbox::solvers::<Reactor>();
+
+#[test]
+fn test_path_counting_logs_each_memo_hit() {
+    use toolbox::Solution;
+    let graph = Reactor::parse(SAMPLE_INPUT).unwrap();
+    let (paths, events) =
+        toolbox::trace::capture(2, || Reactor::part1(&graph).unwrap().to_string());
+    assert_eq!(paths, "5");
+    assert_eq!(
+        events,
+        [
+            "level=debug event=memo_hit node=ddd paths=1",
+            "level=debug event=memo_hit node=eee paths=1"
+        ]
+    );
+    assert_eq!(
+        toolbox::trace::capture(1, || Reactor::part1(&graph).unwrap().to_string()).1,
+        Vec::<String>::new()
     );
 }
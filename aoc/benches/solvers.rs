@@ -0,0 +1,14 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use toolbox::input_store::InputStore;
+
+fn bench_solvers(c: &mut Criterion) {
+    toolbox::bench::bench_registry(c, &aoc::registry(), &InputStore::locate());
+}
+
+fn bench_scaling(c: &mut Criterion) {
+    toolbox::bench::bench_scaling(c, &aoc::registry(), &[10, 100, 1000]);
+}
+
+criterion_group!(benches, bench_solvers, bench_scaling);
+
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+pub fn registry() -> toolbox::Registry {
+    toolbox::Registry::from_iter([
+        secret_entrance::SOLVERS,
+        gift_shop::SOLVERS,
+        lobby::SOLVERS,
+        printing_department::SOLVERS,
+        cafeteria::SOLVERS,
+        trash_compactor::SOLVERS,
+        reactor::SOLVERS,
+    ])
+}
@@ -0,0 +1,231 @@
+const EXECUTABLE_UNDER_TEST: &str = toolbox::binary_path!("aoc");
+
+const TEST_CASES: &[(&[&str], &str, &str)] = &[
+    (
+        &["--day", "1", "--part", "1", "--input", "-"],
+        "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+        "3\n",
+    ),
+    (
+        &["--day", "1", "--input", "-"],
+        "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+        "Day 01 part 1: 3\nDay 01 part 2: 6\n",
+    ),
+    (
+        &["--day", "3", "--part", "2", "--input", "-"],
+        "987654321111111\n",
+        "987654321111\n",
+    ),
+    (
+        &["--day", "5", "--part", "2", "--input", "-"],
+        "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n",
+        "14\n",
+    ),
+    (
+        &["--day", "6", "--part", "1", "--input", "-"],
+        trash_compactor::SAMPLE_INPUT,
+        "4277556\n",
+    ),
+    (
+        &["--day", "6", "--threads", "3", "--input", "-"],
+        trash_compactor::SAMPLE_INPUT,
+        "Day 06 part 1: 4277556\nDay 06 part 2: 3263827\n",
+    ),
+    (
+        &["--day", "11", "--part", "2", "--input", "-"],
+        reactor::SAMPLE_INPUT2,
+        "2\n",
+    ),
+];
+
+#[test]
+fn test_that_the_executable_exists() {
+    assert!(std::fs::exists(EXECUTABLE_UNDER_TEST).unwrap());
+}
+
+#[test]
+fn test_that_the_executable_dispatches_to_the_requested_day_and_part() {
+    for &(args, input, expected_result) in TEST_CASES {
+        let child_output = toolbox::invoke_executable_with_args(EXECUTABLE_UNDER_TEST, args, input);
+        assert_eq!(child_output, expected_result);
+    }
+}
+
+#[test]
+fn test_that_the_executable_reads_inputs_from_the_input_store() {
+    let dir = std::env::temp_dir().join(format!("aoc-input-store-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("2025")).unwrap();
+    std::fs::write(dir.join("2025/03.txt"), "987654321111111\n").unwrap();
+    std::fs::write(dir.join("2025/03.sample.txt"), "811111111111119\n").unwrap();
+    let input_dir = dir.display().to_string();
+
+    let child_output = toolbox::invoke_executable_with_args(
+        EXECUTABLE_UNDER_TEST,
+        &["--day", "3", "--part", "1", "--input-dir", &input_dir],
+        "",
+    );
+    assert_eq!(child_output, "98\n");
+
+    let child_output = toolbox::invoke_executable_with_args(
+        EXECUTABLE_UNDER_TEST,
+        &[
+            "--day",
+            "3",
+            "--part",
+            "1",
+            "--input-dir",
+            &input_dir,
+            "--variant",
+            "sample",
+        ],
+        "",
+    );
+    assert_eq!(child_output, "89\n");
+
+    let output = toolbox::execute(
+        EXECUTABLE_UNDER_TEST,
+        &["--day", "5", "--input-dir", &input_dir],
+        "",
+        None,
+    )
+    .unwrap();
+    assert_eq!(output.exit_code(), Some(1));
+    assert!(output.stderr.contains("no input for day 5"));
+    assert!(output.stderr.contains("05.txt"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_that_the_executable_reports_the_time_spent_on_each_day() {
+    let dir = std::env::temp_dir().join(format!("aoc-report-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("2025")).unwrap();
+    std::fs::write(dir.join("2025/03.txt"), "987654321111111\n").unwrap();
+    let input_dir = dir.display().to_string();
+    let csv = dir.join("report.csv").display().to_string();
+
+    let child_output = toolbox::invoke_executable_with_args(
+        EXECUTABLE_UNDER_TEST,
+        &["report", "--input-dir", &input_dir, "--csv", &csv],
+        "",
+    );
+    assert!(child_output.starts_with("Day  Part        Read       Parse       Solve"));
+    assert!(child_output.contains("\n03   1  "));
+    assert!(child_output.contains("\n03   2  "));
+    assert!(child_output.contains("no input for day 1"));
+    assert!(child_output.contains("Slowest: day 03 part "));
+
+    let csv = std::fs::read_to_string(&csv).unwrap();
+    assert!(csv.starts_with("day,part,read_ns,parse_ns,solve_ns,total_ns,error\n"));
+    assert!(csv.contains("\n3,1,"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_that_no_parser_panics_on_mutated_sample_inputs() {
+    let corpus_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../fuzz/corpus");
+    let output = toolbox::execute(
+        EXECUTABLE_UNDER_TEST,
+        &["fuzz", "--runs", "2000", "--corpus-dir", corpus_dir],
+        "",
+        None,
+    )
+    .unwrap();
+    output.assert_success();
+    assert!(
+        output
+            .stdout
+            .contains("Day 01 part 1: 2000 inputs, no panics\n")
+    );
+    assert!(!output.stdout.contains("panicked"), "{}", output.stdout);
+}
+
+#[test]
+fn test_that_generated_inputs_can_be_piped_back_into_the_executable() {
+    for day in ["1", "2", "3", "4", "5", "6", "11"] {
+        let input = toolbox::invoke_executable_with_args(
+            EXECUTABLE_UNDER_TEST,
+            &["gen", "--day", day, "--size", "30", "--seed", "3"],
+            "",
+        );
+        let child_output = toolbox::invoke_executable_with_args(
+            EXECUTABLE_UNDER_TEST,
+            &["--day", day, "--input", "-"],
+            &input,
+        );
+        assert!(
+            child_output.contains(" part 2: "),
+            "day {day}: {child_output}"
+        );
+    }
+}
+
+#[test]
+fn test_that_every_day_matches_its_snapshot() {
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+    let output = toolbox::execute(
+        EXECUTABLE_UNDER_TEST,
+        &[
+            "snapshot",
+            "--snapshot-dir",
+            &format!("{root}/snapshots"),
+            "--corpus-dir",
+            &format!("{root}/fuzz/corpus"),
+        ],
+        "",
+        None,
+    )
+    .unwrap();
+    assert!(
+        output.exit_code() == Some(0),
+        "{}{}",
+        output.stdout,
+        output.stderr
+    );
+    assert!(output.stdout.contains("Day 05: 4 inputs match\n"));
+}
+
+#[test]
+fn test_that_the_dashboard_shows_the_whole_year() {
+    let dir = std::env::temp_dir().join(format!("aoc-dashboard-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("01.txt"),
+        "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("answers.toml"),
+        "[[answer]]\nday = 1\npart = 1\ninput = \"01.txt\"\nanswer = 3\n\n\
+         [[answer]]\nday = 1\npart = 2\ninput = \"01.txt\"\nanswer = 7\n",
+    )
+    .unwrap();
+    let answers = dir.join("answers.toml").display().to_string();
+    let html = dir.join("year.html").display().to_string();
+
+    let child_output = toolbox::invoke_executable_with_args(
+        EXECUTABLE_UNDER_TEST,
+        &["dashboard", "--answers", &answers, "--html", &html],
+        "",
+    );
+    let lines = child_output.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 14, "{child_output}");
+    assert!(lines[1].starts_with("01   01 - Secret Entrance          verified  "));
+    assert!(lines[1].contains("  FAILED  "));
+    assert!(lines[3].starts_with("03   03 - Lobby                    solved  "));
+    for line in &lines[7..=10] {
+        assert!(line.ends_with("  -                             -                     -"));
+    }
+    assert!(lines[11].starts_with("11   11 - Reactor  "));
+    assert!(lines[12].starts_with("12   -  "));
+    assert!(
+        lines[13]
+            .starts_with("7 of 12 days have crates, 14 of 24 parts solved, 1 verified, 1 failed")
+    );
+
+    let html = std::fs::read_to_string(&html).unwrap();
+    assert!(html.contains("<td>06 - Trash Compactor</td>"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
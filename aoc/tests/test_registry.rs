@@ -0,0 +1,37 @@
+#[test]
+fn test_every_registered_solver_solves_generated_inputs() {
+    let registry = aoc::registry();
+    for solver in registry.iter() {
+        toolbox::random::check(20, |rng| {
+            let Some(input) = (solver.generate)(rng, 50) else {
+                return;
+            };
+            if let Err(e) = solver.run(&input) {
+                panic!("day {} part {}: {e}\n{input}", solver.day, solver.part);
+            }
+        });
+    }
+}
+
+#[test]
+fn test_every_registered_solver_gives_the_same_answers_on_any_number_of_threads() {
+    let registry = aoc::registry();
+    for solver in registry.iter() {
+        toolbox::random::check(3, |rng| {
+            let Some(input) = (solver.generate)(rng, 50) else {
+                return;
+            };
+            let answer = |threads| {
+                toolbox::parallel::with_threads(threads, || solver.run(&input))
+                    .map(|answer| answer.answer)
+            };
+            assert_eq!(
+                answer(1),
+                answer(4),
+                "day {} part {}",
+                solver.day,
+                solver.part
+            );
+        });
+    }
+}
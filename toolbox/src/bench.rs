@@ -0,0 +1,57 @@
+use crate::Registry;
+use crate::input_store::InputStore;
+use crate::random::Rng;
+use criterion::{BenchmarkId, Criterion};
+use std::hint::black_box;
+
+// Parse and solve are measured separately, each under "day NN part P", so every day's numbers
+// line up in one report. Days without a local input are skipped rather than failing the run.
+pub fn bench_registry(c: &mut Criterion, registry: &Registry, store: &InputStore) {
+    for solver in registry.iter() {
+        let input = match store.read(solver.day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("skipping day {:02} part {}: {e}", solver.day, solver.part);
+                continue;
+            }
+        };
+        let parsed = match (solver.parse)(&input) {
+            Ok(parsed) => parsed,
+            Err(message) => {
+                eprintln!(
+                    "skipping day {:02} part {}: failed to parse input: {message}",
+                    solver.day, solver.part
+                );
+                continue;
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("day {:02} part {}", solver.day, solver.part));
+        group.bench_function("parse", |b| b.iter(|| (solver.parse)(black_box(&input))));
+        group.bench_function("solve", |b| {
+            b.iter(|| (solver.solve)(black_box(parsed.as_ref())))
+        });
+        group.finish();
+    }
+}
+
+// Solves generated inputs of each size under "day NN part P scaling", so criterion can plot how
+// the solve time grows. Days without a generator are skipped.
+pub fn bench_scaling(c: &mut Criterion, registry: &Registry, sizes: &[usize]) {
+    for solver in registry.iter() {
+        let mut group = c.benchmark_group(format!(
+            "day {:02} part {} scaling",
+            solver.day, solver.part
+        ));
+        for &size in sizes {
+            let Some(input) = (solver.generate)(&mut Rng::new(0), size) else {
+                break;
+            };
+            let parsed = (solver.parse)(&input).expect("generated input parses");
+            group.bench_with_input(BenchmarkId::from_parameter(size), &parsed, |b, parsed| {
+                b.iter(|| (solver.solve)(black_box(parsed.as_ref())))
+            });
+        }
+        group.finish();
+    }
+}
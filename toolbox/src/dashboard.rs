@@ -0,0 +1,336 @@
+use crate::Registry;
+#[cfg(test)]
+use crate::solver::test_solver;
+use crate::verify::{Outcome, Verification};
+use std::io::Write;
+use std::time::Duration;
+
+pub const DAYS_IN_YEAR: u8 = 12;
+
+// The `NN - Title` members of the workspace manifest, by day. The template is day 0 and left out.
+pub fn workspace_days(manifest: &str) -> Result<Vec<(u8, String)>, String> {
+    let manifest = manifest.parse::<toml::Table>().map_err(|e| e.to_string())?;
+    let members = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("members"))
+        .and_then(|members| members.as_array())
+        .ok_or("no workspace members list")?;
+    let mut days = members
+        .iter()
+        .filter_map(|member| member.as_str())
+        .filter_map(|member| Some((crate::scaffold::day_of(member)?, member.to_string())))
+        .filter(|&(day, _)| (1..=DAYS_IN_YEAR).contains(&day))
+        .collect::<Vec<_>>();
+    days.sort();
+    Ok(days)
+}
+
+#[test]
+fn test_we_can_find_the_days_in_the_workspace() {
+    let manifest = "[workspace]\nresolver = \"3\"\nmembers = [\n    \"00 - Template\",\n    \"11 - Reactor\",\n    \"06 - Trash Compactor\",\n    \"aoc\",\n    \"toolbox\",\n]\n";
+    assert_eq!(
+        workspace_days(manifest),
+        Ok(vec![
+            (6, "06 - Trash Compactor".to_string()),
+            (11, "11 - Reactor".to_string())
+        ])
+    );
+    assert!(workspace_days("[package]\nname = \"aoc\"\n").is_err());
+    assert!(workspace_days("[workspace\n").is_err());
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Unsolved,
+    // A solver is registered, but there is no expected answer or input to check it against.
+    Solved,
+    Verified,
+    Failed,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Unsolved => "-",
+            Status::Solved => "solved",
+            Status::Verified => "verified",
+            Status::Failed => "FAILED",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PartProgress {
+    pub status: Status,
+    pub runtime: Option<Duration>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DayProgress {
+    pub day: u8,
+    pub member: Option<String>,
+    pub parts: [PartProgress; 2],
+}
+
+// A part checked against several inputs fails if any of them does, and its runtime is that of
+// its slowest input, which is the real one rather than a sample.
+fn part_progress(
+    registry: &Registry,
+    verifications: &[Verification],
+    day: u8,
+    part: u8,
+) -> PartProgress {
+    if registry.find(day, part).is_none() {
+        return PartProgress {
+            status: Status::Unsolved,
+            runtime: None,
+        };
+    }
+    let checked = verifications
+        .iter()
+        .filter(|v| v.day == day && v.part == part && (v.passed() || v.failed()))
+        .collect::<Vec<_>>();
+    let status = if checked.iter().any(|v| v.failed()) {
+        Status::Failed
+    } else if checked.is_empty() {
+        Status::Solved
+    } else {
+        Status::Verified
+    };
+    let runtime = checked
+        .iter()
+        .filter(|v| v.outcome != Outcome::Panicked)
+        .map(|v| v.elapsed)
+        .max();
+    PartProgress { status, runtime }
+}
+
+pub fn progress(
+    members: &[(u8, String)],
+    registry: &Registry,
+    verifications: &[Verification],
+) -> Vec<DayProgress> {
+    (1..=DAYS_IN_YEAR)
+        .map(|day| DayProgress {
+            day,
+            member: members
+                .iter()
+                .find(|(d, _)| *d == day)
+                .map(|(_, member)| member.clone()),
+            parts: [1, 2].map(|part| part_progress(registry, verifications, day, part)),
+        })
+        .collect()
+}
+
+fn format_part(part: &PartProgress) -> String {
+    match part.runtime {
+        Some(runtime) => format!("{:<8}  {:>8}", part.status.name(), format!("{runtime:.1?}")),
+        None => part.status.name().to_string(),
+    }
+}
+
+fn summary(days: &[DayProgress]) -> String {
+    let parts = days.iter().flat_map(|day| &day.parts).collect::<Vec<_>>();
+    let count = |status| parts.iter().filter(|part| part.status == status).count();
+    let mut summary = format!(
+        "{} of {} days have crates, {} of {} parts solved, {} verified, {} failed",
+        days.iter().filter(|day| day.member.is_some()).count(),
+        days.len(),
+        parts.len() - count(Status::Unsolved),
+        parts.len(),
+        count(Status::Verified),
+        count(Status::Failed)
+    );
+    let runtimes = parts
+        .iter()
+        .filter_map(|part| part.runtime)
+        .collect::<Vec<_>>();
+    if !runtimes.is_empty() {
+        summary += &format!(", {:.1?} in all", runtimes.iter().sum::<Duration>());
+    }
+    summary
+}
+
+pub fn write_table(days: &[DayProgress], output: &mut dyn Write) -> std::io::Result<()> {
+    writeln!(output, "Day  {:<28}  {:<20}  Part 2", "Crate", "Part 1")?;
+    for day in days {
+        let line = format!(
+            "{:<3}  {:<28}  {:<20}  {}",
+            format!("{:02}", day.day),
+            day.member.as_deref().unwrap_or("-"),
+            format_part(&day.parts[0]),
+            format_part(&day.parts[1])
+        );
+        writeln!(output, "{}", line.trim_end())?;
+    }
+    writeln!(output, "{}", summary(days))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// A page with no scripts or external files, so it can be opened straight from disk or published
+// as it is.
+pub fn write_html(days: &[DayProgress], output: &mut dyn Write) -> std::io::Result<()> {
+    writeln!(
+        output,
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>Advent of Code 2025</title>
+<style>
+body {{ font-family: monospace; background: #0f0f23; color: #cccccc; }}
+table {{ border-collapse: collapse; }}
+th, td {{ padding: 0.2em 1em; text-align: left; }}
+.verified {{ color: #ffff66; }}
+.solved {{ color: #9999cc; }}
+.failed {{ color: #ff6666; }}
+.unsolved, .missing {{ color: #555555; }}
+</style>
+</head>
+<body>
+<h1>Advent of Code 2025</h1>
+<table>
+<tr><th>Day</th><th>Crate</th><th>Part 1</th><th>Part 2</th></tr>"
+    )?;
+    for day in days {
+        let member = match &day.member {
+            Some(member) => format!("<td>{}</td>", escape_html(member)),
+            None => "<td class=\"missing\">-</td>".to_string(),
+        };
+        let parts = day
+            .parts
+            .iter()
+            .map(|part| {
+                let class = format!("{:?}", part.status).to_lowercase();
+                format!(
+                    "<td class=\"{class}\">{}</td>",
+                    escape_html(&format_part(part))
+                )
+            })
+            .collect::<String>();
+        writeln!(output, "<tr><td>{:02}</td>{member}{parts}</tr>", day.day)?;
+    }
+    writeln!(
+        output,
+        "</table>\n<p>{}</p>\n</body>\n</html>",
+        escape_html(&summary(days))
+    )
+}
+
+#[cfg(test)]
+fn test_progress() -> Vec<DayProgress> {
+    let registry = Registry::from_iter([[
+        test_solver!(1, 1, |_| 0),
+        test_solver!(1, 2, |_| 0),
+        test_solver!(2, 1, |_| 0),
+        test_solver!(11, 1, |_| 0),
+    ]
+    .as_slice()]);
+    let verification = |day, part, outcome, millis| Verification {
+        day,
+        part,
+        input: None,
+        outcome,
+        elapsed: Duration::from_millis(millis),
+    };
+    let verifications = [
+        verification(1, 1, Outcome::Pass, 2),
+        verification(1, 1, Outcome::Pass, 1),
+        verification(
+            1,
+            2,
+            Outcome::Fail {
+                expected: "6".to_string(),
+                actual: "5".to_string(),
+            },
+            3,
+        ),
+        verification(2, 1, Outcome::MissingAnswer, 0),
+        verification(3, 1, Outcome::MissingSolver, 0),
+    ];
+    let members = [
+        (1, "01 - Secret Entrance".to_string()),
+        (2, "02 - Gift Shop".to_string()),
+        (11, "11 - Reactor".to_string()),
+    ];
+    progress(&members, &registry, &verifications)
+}
+
+#[test]
+fn test_progress_covers_every_day_of_the_year() {
+    let days = test_progress();
+    assert_eq!(days.len(), 12);
+    assert_eq!(
+        days[0].parts,
+        [
+            PartProgress {
+                status: Status::Verified,
+                runtime: Some(Duration::from_millis(2))
+            },
+            PartProgress {
+                status: Status::Failed,
+                runtime: Some(Duration::from_millis(3))
+            }
+        ]
+    );
+    assert_eq!(days[1].parts[0].status, Status::Solved);
+    assert_eq!(days[1].parts[1].status, Status::Unsolved);
+    assert_eq!(days[2].member, None);
+    assert_eq!(days[2].parts[0].status, Status::Unsolved);
+    assert_eq!(days[10].member.as_deref(), Some("11 - Reactor"));
+}
+
+#[test]
+fn test_the_dashboard_table_shows_gaps_in_the_year() {
+    let mut output = vec![];
+    write_table(&test_progress(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 14);
+    assert_eq!(
+        lines[0],
+        "Day  Crate                         Part 1                Part 2"
+    );
+    assert_eq!(
+        lines[1],
+        "01   01 - Secret Entrance          verified     2.0ms    FAILED       3.0ms"
+    );
+    assert_eq!(
+        lines[2],
+        "02   02 - Gift Shop                solved                -"
+    );
+    assert_eq!(
+        lines[3],
+        "03   -                             -                     -"
+    );
+    assert_eq!(
+        lines[11],
+        "11   11 - Reactor                  solved                -"
+    );
+    assert_eq!(
+        lines[13],
+        "3 of 12 days have crates, 4 of 24 parts solved, 1 verified, 1 failed, 5.0ms in all"
+    );
+}
+
+#[test]
+fn test_the_dashboard_can_be_a_web_page() {
+    let mut output = vec![];
+    write_html(&test_progress(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.starts_with("<!DOCTYPE html>\n"));
+    assert!(output.ends_with("</html>\n"));
+    assert!(output.contains(
+        "<tr><td>01</td><td>01 - Secret Entrance</td><td class=\"verified\">verified     2.0ms</td><td class=\"failed\">FAILED       3.0ms</td></tr>"
+    ));
+    assert!(output.contains(
+        "<tr><td>07</td><td class=\"missing\">-</td><td class=\"unsolved\">-</td><td class=\"unsolved\">-</td></tr>"
+    ));
+    assert_eq!(output.matches("<tr>").count(), 13);
+}
@@ -0,0 +1,368 @@
+use crate::random::Rng;
+use crate::{Registry, Solver, scaffold};
+use std::any::Any;
+use std::io::Write;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const DEFAULT_RUNS: u64 = 10_000;
+
+const DEFAULT_CORPUS_DIR: &str = "fuzz/corpus";
+
+// Characters that tend to break parsers: the puzzles' own separators and symbols, line endings,
+// and characters longer than a byte.
+const INTERESTING: &[char] = &[
+    '0', '1', '9', '-', ',', ':', ' ', '\n', '\r', '\t', '+', '*', 'L', 'R', '.', '@', 'x', 'é',
+    '€', '🎄', '\0',
+];
+
+const INTERESTING_NUMBERS: &[&str] = &["0", "-1", "4294967296", "18446744073709551616"];
+
+// The seed inputs for `day`: every file in `<dir>/<day>/`, such as the puzzle's sample inputs.
+pub struct Corpus {
+    dir: PathBuf,
+}
+
+impl Corpus {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    // The `fuzz/corpus` directory at the root of the workspace.
+    pub fn locate() -> Self {
+        let current_dir = std::env::current_dir().unwrap_or_default();
+        let root = scaffold::find_workspace_root(&current_dir).unwrap_or(current_dir);
+        Self::new(root.join(DEFAULT_CORPUS_DIR))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn seeds(&self, day: u8) -> Vec<String> {
+        self.seed_files(day)
+            .into_iter()
+            .map(|(_, contents)| contents)
+            .collect()
+    }
+
+    // Each seed with its file name, in file name order.
+    pub fn seed_files(&self, day: u8) -> Vec<(String, String)> {
+        let Ok(entries) = std::fs::read_dir(self.dir.join(format!("{day:02}"))) else {
+            return vec![];
+        };
+        let mut paths = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+            .iter()
+            .filter_map(|path| {
+                let name = path.file_name()?.to_string_lossy().into_owned();
+                Some((name, std::fs::read_to_string(path).ok()?))
+            })
+            .collect()
+    }
+}
+
+// Applies a few random edits to one of the seeds, keeping the result valid UTF-8 since the
+// parsers take `&str`.
+pub fn mutate(rng: &mut Rng, seeds: &[String]) -> String {
+    let mut chars = rng.pick(seeds).chars().collect::<Vec<_>>();
+    for _ in 0..1 + rng.below(4) {
+        let at = rng.below(chars.len() + 1);
+        let len = rng.below(chars.len() - at + 1).min(8);
+        match rng.below(6) {
+            0 => {
+                chars.drain(at..at + len);
+            }
+            1 => chars.insert(at, *rng.pick(INTERESTING)),
+            2 if at < chars.len() => chars[at] = *rng.pick(INTERESTING),
+            3 => {
+                let span = chars[at..at + len].to_vec();
+                chars.splice(at..at, span);
+            }
+            4 => {
+                let other = rng.pick(seeds).chars().collect::<Vec<_>>();
+                let from = rng.below(other.len() + 1);
+                chars.splice(at.., other[from..].iter().copied());
+            }
+            _ => {
+                chars.splice(at..at + len, rng.pick(INTERESTING_NUMBERS).chars());
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[test]
+fn test_mutations_start_from_a_seed_and_are_repeatable() {
+    let seeds = vec!["L68\nR48\n".to_string()];
+    let mutants = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..100)
+            .map(|_| mutate(&mut rng, &seeds))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(mutants(1), mutants(1));
+    assert!(mutants(1).iter().any(|mutant| mutant != &seeds[0]));
+    let mut rng = Rng::new(2);
+    for _ in 0..1000 {
+        mutate(&mut rng, &[String::new(), "é".to_string()]);
+    }
+}
+
+// A parser for one piece of a day's input. Only whether it panics matters, so its result is
+// dropped.
+#[derive(Clone, Copy, Debug)]
+pub struct Target {
+    pub name: &'static str,
+    pub parse: fn(&str),
+}
+
+fn parse_as<T: FromStr>(input: &str) {
+    let _ = input.parse::<T>();
+}
+
+impl Target {
+    pub const fn from_str<T: FromStr>(name: &'static str) -> Self {
+        Self {
+            name,
+            parse: parse_as::<T>,
+        }
+    }
+}
+
+// Seeds for a target: the seeds themselves, their lines, and the fields of those lines.
+fn pieces(seeds: &[String]) -> Vec<String> {
+    let mut pieces = seeds.to_vec();
+    for seed in seeds {
+        pieces.extend(seed.lines().map(str::to_string));
+        pieces.extend(
+            seed.split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|field| !field.is_empty())
+                .map(str::to_string),
+        );
+    }
+    pieces.sort();
+    pieces.dedup();
+    pieces
+}
+
+#[test]
+fn test_targets_are_seeded_with_pieces_of_the_inputs() {
+    assert_eq!(
+        pieces(&["11-22,95-115\n".to_string(), "a: b\n".to_string()]),
+        [
+            "11-22",
+            "11-22,95-115",
+            "11-22,95-115\n",
+            "95-115",
+            "a:",
+            "a: b",
+            "a: b\n",
+            "b"
+        ]
+    );
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Crash {
+    pub day: u8,
+    // `part 1`, `part 2`, or the name of a target.
+    pub parser: String,
+    pub input: String,
+    pub message: String,
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked".to_string())
+}
+
+// Feeds `runs` mutated seeds to `parse`, which may accept or reject each one but must not
+// panic. Stops at the first panic, as later ones are usually the same bug, and returns the input
+// and the panic's message.
+fn fuzz_fn(
+    parse: impl Fn(&str),
+    seeds: &[String],
+    runs: u64,
+    seed: u64,
+) -> Option<(String, String)> {
+    let empty = [String::new()];
+    let seeds = if seeds.is_empty() { &empty[..] } else { seeds };
+    let mut rng = Rng::new(seed);
+    let inputs = seeds
+        .iter()
+        .cloned()
+        .chain(std::iter::repeat_with(|| mutate(&mut rng, seeds)));
+    for input in inputs.take(runs.try_into().unwrap_or(usize::MAX)) {
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(|| parse(&input))) {
+            return Some((input, panic_message(panic.as_ref())));
+        }
+    }
+    None
+}
+
+pub fn fuzz_parser(solver: &Solver, seeds: &[String], runs: u64, seed: u64) -> Option<Crash> {
+    let (input, message) = fuzz_fn(
+        |input| {
+            let _ = (solver.parse)(input);
+        },
+        seeds,
+        runs,
+        seed,
+    )?;
+    Some(Crash {
+        day: solver.day,
+        parser: format!("part {}", solver.part),
+        input,
+        message,
+    })
+}
+
+pub fn fuzz_target(
+    day: u8,
+    target: &Target,
+    seeds: &[String],
+    runs: u64,
+    seed: u64,
+) -> Option<Crash> {
+    let (input, message) = fuzz_fn(target.parse, &pieces(seeds), runs, seed)?;
+    Some(Crash {
+        day,
+        parser: target.name.to_string(),
+        input,
+        message,
+    })
+}
+
+pub fn fuzz(
+    registry: &Registry,
+    corpus: &Corpus,
+    day: Option<u8>,
+    runs: u64,
+    seed: u64,
+    output: &mut dyn Write,
+) -> std::io::Result<Vec<Crash>> {
+    let mut crashes = vec![];
+    let solvers = registry
+        .iter()
+        .filter(|solver| day.is_none_or(|day| solver.day == day))
+        .collect::<Vec<_>>();
+    for (i, solver) in solvers.iter().enumerate() {
+        let seeds = corpus.seeds(solver.day);
+        let mut results = vec![(
+            format!("part {}", solver.part),
+            fuzz_parser(solver, &seeds, runs, seed),
+        )];
+        // A day's parts share their targets, so they are fuzzed after its first part.
+        if i == 0 || solvers[i - 1].day != solver.day {
+            for target in solver.fuzz_targets {
+                results.push((
+                    target.name.to_string(),
+                    fuzz_target(solver.day, target, &seeds, runs, seed),
+                ));
+            }
+        }
+        for (parser, crash) in results {
+            let label = format!("Day {:02} {parser}", solver.day);
+            match crash {
+                None => writeln!(output, "{label}: {runs} inputs, no panics")?,
+                Some(crash) => {
+                    writeln!(
+                        output,
+                        "{label}: panicked on {:?}: {}",
+                        crash.input, crash.message
+                    )?;
+                    crashes.push(crash);
+                }
+            }
+        }
+    }
+    Ok(crashes)
+}
+
+#[cfg(test)]
+fn fragile_solver() -> Solver {
+    Solver {
+        parse: |input| {
+            let first = input.split(',').next().unwrap_or_default();
+            Ok(Box::new(first[..1].to_string()))
+        },
+        ..crate::solver::test_solver!(7, 1, |input: &str| input.len())
+    }
+}
+
+#[test]
+fn test_the_fuzzer_finds_a_parser_that_panics() {
+    let crash = fuzz_parser(&fragile_solver(), &["a,b".to_string()], 1000, 0).unwrap();
+    assert_eq!((crash.day, crash.parser.as_str()), (7, "part 1"));
+    assert!(!crash.input.starts_with('a'), "{:?}", crash.input);
+    assert!(crash.message.contains("byte index"), "{}", crash.message);
+
+    let sturdy = crate::solver::test_solver!(7, 2, |input: &str| input.len());
+    assert_eq!(fuzz_parser(&sturdy, &["a,b".to_string()], 1000, 0), None);
+}
+
+#[cfg(test)]
+const TEST_TARGETS: &[Target] = &[
+    Target::from_str::<u8>("u8"),
+    Target {
+        name: "first byte",
+        parse: |input| {
+            let _ = &input[..1];
+        },
+    },
+];
+
+#[test]
+fn test_the_fuzzer_reads_seeds_and_reports_each_solver() {
+    let dir = std::env::temp_dir().join(format!("toolbox-fuzz-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("07")).unwrap();
+    std::fs::write(dir.join("07/b.txt"), "c,d").unwrap();
+    std::fs::write(dir.join("07/a.txt"), "a,b").unwrap();
+    let corpus = Corpus::new(&dir);
+    assert_eq!(corpus.seeds(7), ["a,b", "c,d"]);
+    assert_eq!(corpus.seeds(8), Vec::<String>::new());
+    assert_eq!(
+        corpus.seed_files(7),
+        [
+            ("a.txt".to_string(), "a,b".to_string()),
+            ("b.txt".to_string(), "c,d".to_string())
+        ]
+    );
+
+    let registry = Registry::from_iter([[
+        Solver {
+            fuzz_targets: TEST_TARGETS,
+            ..fragile_solver()
+        },
+        crate::solver::test_solver!(7, 2, |input: &str| input.len()),
+        crate::solver::test_solver!(8, 1, |input: &str| input.len()),
+    ]
+    .as_slice()]);
+    let mut output = vec![];
+    let crashes = fuzz(&registry, &corpus, Some(7), 500, 0, &mut output).unwrap();
+    assert_eq!(crashes.len(), 2);
+    assert_eq!(crashes[1].parser, "first byte");
+    let output = String::from_utf8(output).unwrap();
+    assert!(
+        output.starts_with("Day 07 part 1: panicked on "),
+        "{output}"
+    );
+    assert!(
+        output.contains("\nDay 07 u8: 500 inputs, no panics\nDay 07 first byte: panicked on "),
+        "{output}"
+    );
+    assert!(
+        output.ends_with("Day 07 part 2: 500 inputs, no panics\n"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,416 @@
+use crate::ParseError;
+use crate::memo::Memo;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle(pub Vec<NodeId>);
+
+#[derive(Clone, Debug)]
+pub struct Graph<N> {
+    names: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    successors: Vec<Vec<NodeId>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            names: vec![],
+            ids: HashMap::new(),
+            successors: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, name: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+        let id = NodeId(self.names.len());
+        self.names.push(name.clone());
+        self.ids.insert(name, id);
+        self.successors.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, name: &N) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &N {
+        &self.names[id.0]
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId) {
+        self.successors[from.0].push(to);
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> + use<N> {
+        (0..self.names.len()).map(NodeId)
+    }
+
+    pub fn successors(&self, id: NodeId) -> &[NodeId] {
+        &self.successors[id.0]
+    }
+
+    pub fn breadth_first(&self, start: NodeId) -> Vec<(NodeId, usize)> {
+        let mut seen = vec![false; self.len()];
+        let mut visited = vec![];
+        let mut queue = VecDeque::from([(start, 0)]);
+        seen[start.0] = true;
+        while let Some((node, distance)) = queue.pop_front() {
+            visited.push((node, distance));
+            for &next in self.successors(node) {
+                if !std::mem::replace(&mut seen[next.0], true) {
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        visited
+    }
+
+    pub fn depth_first(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut visited = vec![];
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            if std::mem::replace(&mut seen[node.0], true) {
+                continue;
+            }
+            visited.push(node);
+            stack.extend(self.successors(node).iter().rev());
+        }
+        visited
+    }
+
+    pub fn topological_order(&self) -> Result<Vec<NodeId>, Cycle> {
+        let mut search = Search::new(self.len());
+        for node in self.nodes() {
+            self.visit(node, &mut search)?;
+        }
+        search.finished.reverse();
+        Ok(search.finished)
+    }
+
+    pub fn find_cycle(&self) -> Option<Cycle> {
+        self.topological_order().err()
+    }
+
+    fn visit(&self, node: NodeId, search: &mut Search) -> Result<(), Cycle> {
+        if search.done[node.0] {
+            return Ok(());
+        }
+        search.enter(node)?;
+        for &next in self.successors(node) {
+            self.visit(next, search)?;
+        }
+        search.leave(node);
+        search.finished.push(node);
+        Ok(())
+    }
+}
+
+impl<N: Clone + Eq + Hash + Display> Graph<N> {
+    // Paths stop when they reach `to`, so only a cycle on the way from `from` to `to` is an error.
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> Result<usize, Cycle> {
+        self.count_paths_from(from, to, &mut Memo::new(), &mut Search::new(self.len()))
+    }
+
+    fn count_paths_from(
+        &self,
+        node: NodeId,
+        to: NodeId,
+        counts: &mut Memo<NodeId, usize>,
+        search: &mut Search,
+    ) -> Result<usize, Cycle> {
+        if node == to {
+            return Ok(1);
+        }
+        if let Some(count) = counts.get(&node) {
+            crate::event!(Debug, "memo_hit", node = self.name(node), paths = count);
+        }
+        counts.try_get_or_insert_with(node, |counts| {
+            search.enter(node)?;
+            let mut count = 0;
+            for &next in self.successors(node) {
+                count += self.count_paths_from(next, to, counts, search)?;
+            }
+            search.leave(node);
+            Ok(count)
+        })
+    }
+}
+
+impl Graph<String> {
+    pub fn parse_adjacency_list(input: &str, expected: &str) -> Result<Self, ParseError> {
+        let mut graph = Graph::new();
+        crate::parse_lines(input, |line| {
+            let (name, successors) = crate::parse::key_values(
+                line,
+                expected,
+                |name| match name.trim() {
+                    "" => Err(ParseError::expected("a node name")),
+                    name => Ok(name.to_string()),
+                },
+                |successor| Ok::<_, ParseError>(successor.to_string()),
+            )?;
+            let from = graph.intern(name);
+            for successor in successors {
+                let to = graph.intern(successor);
+                graph.add_edge(from, to);
+            }
+            Ok::<_, ParseError>(())
+        })?;
+        Ok(graph)
+    }
+}
+
+impl<N: Clone + Eq + Hash> PartialEq for Graph<N> {
+    fn eq(&self, other: &Self) -> bool {
+        let names = |graph: &Self, ids: &[NodeId]| {
+            ids.iter()
+                .map(|&id| graph.name(id).clone())
+                .collect::<Vec<_>>()
+        };
+        self.len() == other.len()
+            && self.nodes().all(|node| {
+                other.id(self.name(node)).is_some_and(|other_node| {
+                    names(self, self.successors(node)) == names(other, other.successors(other_node))
+                })
+            })
+    }
+}
+
+impl<N: Clone + Eq + Hash> Eq for Graph<N> {}
+
+struct Search {
+    on_path: Vec<bool>,
+    path: Vec<NodeId>,
+    done: Vec<bool>,
+    finished: Vec<NodeId>,
+}
+
+impl Search {
+    fn new(len: usize) -> Self {
+        Self {
+            on_path: vec![false; len],
+            path: vec![],
+            done: vec![false; len],
+            finished: vec![],
+        }
+    }
+
+    fn enter(&mut self, node: NodeId) -> Result<(), Cycle> {
+        if self.on_path[node.0] {
+            let start = self.path.iter().position(|&n| n == node).unwrap();
+            return Err(Cycle(self.path[start..].to_vec()));
+        }
+        self.on_path[node.0] = true;
+        self.path.push(node);
+        Ok(())
+    }
+
+    fn leave(&mut self, node: NodeId) {
+        self.on_path[node.0] = false;
+        self.path.pop();
+        self.done[node.0] = true;
+    }
+}
+
+#[cfg(test)]
+const LINE: &str = "a node and its successors, like `aaa: bbb ccc`";
+
+#[cfg(test)]
+const SAMPLE: &str = "aaa: you hhh\nyou: bbb ccc\nbbb: ddd eee\nccc: ddd eee fff\nddd: ggg\neee: out\nfff: out\nggg: out\nhhh: ccc fff iii\niii: out\n";
+
+#[cfg(test)]
+fn node(graph: &Graph<String>, name: &str) -> NodeId {
+    graph.id(&name.to_string()).unwrap()
+}
+
+#[cfg(test)]
+fn names(graph: &Graph<String>, ids: impl IntoIterator<Item = NodeId>) -> Vec<&str> {
+    ids.into_iter().map(|id| graph.name(id).as_str()).collect()
+}
+
+#[test]
+fn test_we_can_parse_an_adjacency_list() {
+    let graph = Graph::parse_adjacency_list("a: b c\nb: c\n", LINE).unwrap();
+    assert_eq!(graph.len(), 3);
+    assert_eq!(
+        names(&graph, graph.successors(node(&graph, "a")).to_vec()),
+        ["b", "c"]
+    );
+    assert!(graph.successors(node(&graph, "c")).is_empty());
+    assert_eq!(graph.id(&"d".to_string()), None);
+
+    assert_eq!(
+        Graph::parse_adjacency_list("a: b\nb c\n", LINE)
+            .unwrap_err()
+            .to_string(),
+        "line 2: expected a node and its successors, like `aaa: bbb ccc`, found \"b c\""
+    );
+    assert_eq!(
+        Graph::parse_adjacency_list("a: b\n : b\n", LINE)
+            .unwrap_err()
+            .to_string(),
+        "line 2, column 1: expected a node name, found \" \""
+    );
+    assert_eq!(Graph::parse_adjacency_list("", LINE), Ok(Graph::new()));
+}
+
+#[test]
+fn test_graphs_with_the_same_edges_are_equal() {
+    let mut graph = Graph::new();
+    let (b, a, c) = (
+        graph.intern("b".to_string()),
+        graph.intern("a".to_string()),
+        graph.intern("c".to_string()),
+    );
+    graph.add_edge(a, b);
+    graph.add_edge(a, c);
+    graph.add_edge(b, c);
+    assert_eq!(
+        graph,
+        Graph::parse_adjacency_list("a: b c\nb: c\n", LINE).unwrap()
+    );
+    assert_ne!(
+        graph,
+        Graph::parse_adjacency_list("a: b c\n", LINE).unwrap()
+    );
+    assert_ne!(
+        graph,
+        Graph::parse_adjacency_list("a: c b\nb: c\n", LINE).unwrap()
+    );
+}
+
+#[test]
+fn test_we_can_search_breadth_and_depth_first() {
+    let graph = Graph::parse_adjacency_list(SAMPLE, LINE).unwrap();
+    let you = node(&graph, "you");
+
+    let breadth_first = graph.breadth_first(you);
+    assert_eq!(
+        names(&graph, breadth_first.iter().map(|&(id, _)| id)),
+        ["you", "bbb", "ccc", "ddd", "eee", "fff", "ggg", "out"]
+    );
+    assert_eq!(
+        breadth_first
+            .iter()
+            .map(|&(_, distance)| distance)
+            .collect::<Vec<_>>(),
+        [0, 1, 1, 2, 2, 2, 3, 3]
+    );
+    assert_eq!(
+        names(&graph, graph.depth_first(you)),
+        ["you", "bbb", "ddd", "ggg", "out", "eee", "ccc", "fff"]
+    );
+    assert_eq!(
+        names(&graph, graph.depth_first(node(&graph, "out"))),
+        ["out"]
+    );
+}
+
+#[test]
+fn test_a_topological_order_puts_every_node_before_its_successors() {
+    let graph = Graph::parse_adjacency_list(SAMPLE, LINE).unwrap();
+    let order = graph.topological_order().unwrap();
+    assert_eq!(order.len(), graph.len());
+    let position = |id: NodeId| order.iter().position(|&n| n == id).unwrap();
+    for from in graph.nodes() {
+        for &to in graph.successors(from) {
+            assert!(position(from) < position(to));
+        }
+    }
+    assert_eq!(graph.find_cycle(), None);
+}
+
+#[test]
+fn test_we_can_find_a_cycle() {
+    let graph = Graph::parse_adjacency_list("a: b\nb: c\nc: d b\nd:\n", LINE).unwrap();
+    let cycle = graph.find_cycle().unwrap();
+    assert_eq!(names(&graph, cycle.0.clone()), ["b", "c"]);
+    assert_eq!(graph.topological_order(), Err(cycle));
+
+    let graph = Graph::parse_adjacency_list("a: a\n", LINE).unwrap();
+    assert_eq!(names(&graph, graph.find_cycle().unwrap().0), ["a"]);
+}
+
+#[test]
+fn test_we_can_count_the_paths_through_a_dag() {
+    let graph = Graph::parse_adjacency_list(SAMPLE, LINE).unwrap();
+    let count = |from, to| graph.count_paths(node(&graph, from), node(&graph, to));
+    assert_eq!(count("you", "out"), Ok(5));
+    assert_eq!(count("aaa", "out"), Ok(10));
+    assert_eq!(count("out", "out"), Ok(1));
+    assert_eq!(count("out", "you"), Ok(0));
+    assert_eq!(count("bbb", "ccc"), Ok(0));
+
+    let graph = Graph::parse_adjacency_list("a: b c\nb: d\nc: d\nd: b\n", LINE).unwrap();
+    assert_eq!(
+        graph.count_paths(node(&graph, "a"), node(&graph, "b")),
+        Ok(2)
+    );
+    assert!(
+        graph
+            .count_paths(node(&graph, "a"), node(&graph, "c"))
+            .is_err()
+    );
+}
+
+#[cfg(test)]
+fn count_paths_one_by_one(graph: &Graph<String>, from: NodeId, to: NodeId) -> usize {
+    if from == to {
+        return 1;
+    }
+    graph
+        .successors(from)
+        .iter()
+        .map(|&next| count_paths_one_by_one(graph, next, to))
+        .sum()
+}
+
+#[test]
+fn test_counting_paths_agrees_with_walking_each_one() {
+    use crate::random;
+    random::check(1000, |rng| {
+        let graph = random::dag(rng, 10, 35);
+        let order = graph.topological_order().unwrap();
+        let position = |id: NodeId| order.iter().position(|&n| n == id).unwrap();
+        for from in graph.nodes() {
+            for &to in graph.successors(from) {
+                assert!(position(from) < position(to));
+            }
+        }
+
+        let (from, to) = (NodeId(rng.below(10)), NodeId(rng.below(10)));
+        assert_eq!(
+            graph.count_paths(from, to),
+            Ok(count_paths_one_by_one(&graph, from, to))
+        );
+    });
+}
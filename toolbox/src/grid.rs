@@ -0,0 +1,415 @@
+use crate::ParseError;
+use std::ops::{Index, IndexMut};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn offset(&self, (rows, cols): (isize, isize)) -> Option<Position> {
+        Some(Position {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+}
+
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, ParseError> {
+        let mut grid = Grid {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        };
+        for row in rows {
+            if grid.height == 0 {
+                grid.width = row.len();
+            } else if row.len() != grid.width {
+                return Err(
+                    ParseError::expected(format!("a row of {} cells", grid.width))
+                        .with_line(grid.height + 1),
+                );
+            }
+            grid.cells.extend(row);
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    fn parse_row<E: Into<ParseError>>(
+        line: &str,
+        cell: &mut impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Vec<T>, ParseError> {
+        line.chars()
+            .enumerate()
+            .map(|(index, c)| cell(c).map_err(|e| e.into().with_column(index + 1)))
+            .collect()
+    }
+
+    pub fn parse<E: Into<ParseError>>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let rows = crate::parse_lines(input, |line| Self::parse_row(line, &mut cell))?;
+        Self::from_rows(rows).map_err(|e| {
+            let line = e.line.expect("a ragged row has a line");
+            e.with_text(input.lines().nth(line - 1).unwrap_or_default())
+        })
+    }
+
+    pub fn parse_sparse<E: Into<ParseError>>(
+        input: &str,
+        fill: T,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let rows = crate::parse_lines(input, |line| Self::parse_row(line, &mut cell))?;
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        Self::from_rows(rows.into_iter().map(|mut row| {
+            row.resize(width, fill.clone());
+            row
+        }))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height && position.col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.row * self.width + position.col])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.row * self.width + position.col])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Position { row, col }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} is outside the grid");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    fn neighbours<'a>(
+        &self,
+        position: Position,
+        steps: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + use<'a, T> {
+        let (width, height) = (self.width, self.height);
+        steps
+            .iter()
+            .filter_map(move |&step| position.offset(step))
+            .filter(move |p| p.row < height && p.col < width)
+    }
+
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + use<T> {
+        self.neighbours(position, &NEIGHBOURS_4)
+    }
+
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + use<T> {
+        self.neighbours(position, &NEIGHBOURS_8)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| Position { row, col }))
+            .map(|position| self[source(position)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |p| Position::new(p.col, p.row))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |p| {
+            Position::new(height - 1 - p.col, p.row)
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |p| {
+            Position::new(p.col, width - 1 - p.row)
+        })
+    }
+
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+fn roll(c: char) -> Result<bool, ParseError> {
+    match c {
+        '.' => Ok(false),
+        '@' => Ok(true),
+        _ => Err(ParseError::expected("`.` or `@`")),
+    }
+}
+
+#[test]
+fn test_we_can_parse_a_grid_from_a_character_map() {
+    let grid = Grid::parse(".@.\n@@.\n", roll).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert!(grid[Position::new(0, 1)]);
+    assert!(!grid[Position::new(1, 2)]);
+    assert_eq!(grid.get(Position::new(2, 0)), None);
+    assert_eq!(Grid::parse("", roll).unwrap(), Grid::default());
+
+    assert_eq!(
+        Grid::parse(".@.\n@x.\n", roll).unwrap_err().to_string(),
+        "line 2, column 2: expected `.` or `@`, found \"@x.\""
+    );
+    assert_eq!(
+        Grid::parse(".@.\n@@\n", roll).unwrap_err().to_string(),
+        "line 2: expected a row of 3 cells, found \"@@\""
+    );
+}
+
+#[test]
+fn test_we_can_parse_a_sparse_character_map() {
+    let grid = Grid::parse_sparse("@\n.@@\n\n", false, roll).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert_eq!(
+        grid.render(|&roll| if roll { '@' } else { '.' }),
+        "@..\n.@@\n...\n"
+    );
+    assert_eq!(
+        Grid::parse_sparse("", false, roll).unwrap(),
+        Grid::default()
+    );
+    assert_eq!(
+        Grid::parse_sparse("@\n.x\n", false, roll)
+            .unwrap_err()
+            .to_string(),
+        "line 2, column 2: expected `.` or `@`, found \".x\""
+    );
+}
+
+#[test]
+fn test_we_can_build_a_grid_from_rows() {
+    let grid = Grid::from_rows([vec![1, 2], vec![3, 4]]).unwrap();
+    assert_eq!(grid[Position::new(1, 0)], 3);
+    assert_eq!(
+        Grid::from_rows([vec![1, 2], vec![3]]).unwrap_err(),
+        ParseError::expected("a row of 2 cells").with_line(2)
+    );
+    assert_eq!(Grid::new(2, 1, 'x').render(|&c| c), "xx\n");
+}
+
+#[test]
+fn test_neighbours_stay_inside_the_grid() {
+    let grid = Grid::new(3, 3, 0);
+    let neighbours = |position, eight: bool| {
+        let mut neighbours = if eight {
+            grid.neighbours_8(position).collect::<Vec<_>>()
+        } else {
+            grid.neighbours_4(position).collect::<Vec<_>>()
+        };
+        neighbours.sort();
+        neighbours
+    };
+
+    assert_eq!(neighbours(Position::new(1, 1), true).len(), 8);
+    assert_eq!(
+        neighbours(Position::new(1, 1), false),
+        vec![
+            Position::new(0, 1),
+            Position::new(1, 0),
+            Position::new(1, 2),
+            Position::new(2, 1)
+        ]
+    );
+    assert_eq!(
+        neighbours(Position::new(0, 0), true),
+        vec![
+            Position::new(0, 1),
+            Position::new(1, 0),
+            Position::new(1, 1)
+        ]
+    );
+    assert_eq!(neighbours(Position::new(2, 2), false).len(), 2);
+    assert_eq!(neighbours(Position::new(0, 1), true).len(), 5);
+}
+
+#[test]
+fn test_we_can_view_rows_and_columns() {
+    let grid = Grid::from_rows([vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(grid.row(1), [4, 5, 6]);
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(grid.column(2).collect::<Vec<_>>(), [&3, &6]);
+    assert_eq!(
+        grid.columns()
+            .map(|column| column.sum::<i32>())
+            .collect::<Vec<_>>(),
+        [5, 7, 9]
+    );
+    assert_eq!(
+        grid.iter()
+            .filter(|(_, value)| **value % 2 == 0)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>(),
+        [
+            Position::new(0, 1),
+            Position::new(1, 0),
+            Position::new(1, 2)
+        ]
+    );
+}
+
+#[test]
+fn test_we_can_transpose_and_rotate_a_grid() {
+    let grid = Grid::parse("ab\ncd\nef\n", Ok::<_, ParseError>).unwrap();
+    let render = |grid: Grid<char>| grid.render(|&c| c);
+
+    assert_eq!(render(grid.transpose()), "ace\nbdf\n");
+    assert_eq!(render(grid.rotate_clockwise()), "eca\nfdb\n");
+    assert_eq!(render(grid.rotate_counterclockwise()), "bdf\nace\n");
+    assert_eq!(
+        grid.rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise()
+            .rotate_clockwise(),
+        grid
+    );
+    assert_eq!(grid.transpose().transpose(), grid);
+    assert_eq!(Grid::<char>::default().transpose(), Grid::default());
+}
+
+#[test]
+fn test_we_can_map_and_render_a_grid() {
+    let grid = Grid::parse(".@\n@.\n", roll).unwrap();
+    assert_eq!(
+        grid.map(|&roll| !roll)
+            .render(|&roll| if roll { '@' } else { '.' }),
+        "@.\n.@\n"
+    );
+}
+
+#[test]
+fn test_rotations_move_each_cell_where_expected() {
+    use crate::random;
+    random::check(500, |rng| {
+        let (width, height) = (1 + rng.below(6), 1 + rng.below(6));
+        let grid = random::grid(rng, width, height, &[0, 1, 2, 3]);
+        let (clockwise, transposed) = (grid.rotate_clockwise(), grid.transpose());
+        for (Position { row, col }, cell) in grid.iter() {
+            assert_eq!(clockwise[Position::new(col, height - 1 - row)], *cell);
+            assert_eq!(transposed[Position::new(col, row)], *cell);
+        }
+        assert_eq!(clockwise.rotate_counterclockwise(), grid);
+    });
+}
@@ -0,0 +1,150 @@
+use crate::scaffold;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+pub const YEAR: u16 = 2025;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "input";
+
+#[derive(Debug, PartialEq)]
+pub enum InputError {
+    Missing {
+        day: u8,
+        variant: Option<String>,
+        path: PathBuf,
+    },
+    Unreadable {
+        path: PathBuf,
+        message: String,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing {
+                day,
+                variant: None,
+                path,
+            } => write!(
+                f,
+                "no input for day {day}: expected {} (save your puzzle input there or set {INPUT_DIR_VAR})",
+                path.display()
+            ),
+            InputError::Missing {
+                day,
+                variant: Some(variant),
+                path,
+            } => write!(
+                f,
+                "no {variant} input for day {day}: expected {}",
+                path.display()
+            ),
+            InputError::Unreadable { path, message } => {
+                write!(f, "failed to read {}: {message}", path.display())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputStore {
+    dir: PathBuf,
+}
+
+impl InputStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    // $AOC_INPUT_DIR if it is set, otherwise the `input` directory at the root of the
+    // workspace, so binaries, tests and benches all find the same files wherever they run.
+    pub fn locate() -> Self {
+        if let Some(dir) = std::env::var_os(INPUT_DIR_VAR).filter(|dir| !dir.is_empty()) {
+            return Self::new(dir);
+        }
+        let current_dir = std::env::current_dir().unwrap_or_default();
+        let root = scaffold::find_workspace_root(&current_dir).unwrap_or(current_dir);
+        Self::new(root.join(DEFAULT_INPUT_DIR))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn relative_path(day: u8, variant: Option<&str>) -> PathBuf {
+        let file_name = match variant {
+            None => format!("{day:02}.txt"),
+            Some(variant) => format!("{day:02}.{variant}.txt"),
+        };
+        Path::new(&YEAR.to_string()).join(file_name)
+    }
+
+    pub fn path(&self, day: u8, variant: Option<&str>) -> PathBuf {
+        self.dir.join(Self::relative_path(day, variant))
+    }
+
+    pub fn read(&self, day: u8, variant: Option<&str>) -> Result<String, InputError> {
+        let path = self.path(day, variant);
+        std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => InputError::Missing {
+                day,
+                variant: variant.map(str::to_string),
+                path,
+            },
+            _ => InputError::Unreadable {
+                path,
+                message: e.to_string(),
+            },
+        })
+    }
+}
+
+#[test]
+fn test_the_store_lays_out_inputs_by_year_and_day() {
+    let store = InputStore::new("/puzzles");
+    assert_eq!(store.path(5, None), PathBuf::from("/puzzles/2025/05.txt"));
+    assert_eq!(
+        store.path(11, Some("sample")),
+        PathBuf::from("/puzzles/2025/11.sample.txt")
+    );
+    assert_eq!(
+        InputStore::relative_path(1, None),
+        PathBuf::from("2025/01.txt")
+    );
+}
+
+#[test]
+fn test_we_can_read_inputs_from_the_store() {
+    let dir = std::env::temp_dir().join(format!("toolbox-input-store-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("2025")).unwrap();
+    std::fs::write(dir.join("2025/05.txt"), "real\n").unwrap();
+    std::fs::write(dir.join("2025/05.sample.txt"), "sample\n").unwrap();
+
+    let store = InputStore::new(&dir);
+    assert_eq!(store.read(5, None), Ok("real\n".to_string()));
+    assert_eq!(store.read(5, Some("sample")), Ok("sample\n".to_string()));
+    assert_eq!(
+        store.read(6, None),
+        Err(InputError::Missing {
+            day: 6,
+            variant: None,
+            path: dir.join("2025/06.txt")
+        })
+    );
+
+    let message = store.read(6, Some("sample")).unwrap_err().to_string();
+    assert!(message.contains("no sample input for day 6"));
+    assert!(message.contains("06.sample.txt"));
+    assert!(
+        store
+            .read(6, None)
+            .unwrap_err()
+            .to_string()
+            .contains(INPUT_DIR_VAR)
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
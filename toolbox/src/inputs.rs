@@ -0,0 +1,546 @@
+use crate::{Answer, LineError, Solution, Solver};
+use serde::Serialize;
+use std::fmt::Display;
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Vec<InputSource> {
+        let sources = args
+            .into_iter()
+            .map(|arg| match arg.as_str() {
+                "-" => InputSource::Stdin,
+                _ => InputSource::File(PathBuf::from(arg)),
+            })
+            .collect::<Vec<_>>();
+
+        if sources.is_empty() {
+            vec![InputSource::Stdin]
+        } else {
+            sources
+        }
+    }
+
+    pub fn read(&self) -> std::io::Result<String> {
+        match self {
+            InputSource::Stdin => crate::read_to_string(&mut std::io::stdin().lock()),
+            InputSource::File(path) => std::fs::read_to_string(path),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[test]
+fn test_we_can_name_input_sources_on_the_command_line() {
+    let args = |args: &[&str]| InputSource::from_args(args.iter().map(|arg| arg.to_string()));
+
+    assert_eq!(args(&[]), vec![InputSource::Stdin]);
+    assert_eq!(args(&["-"]), vec![InputSource::Stdin]);
+    assert_eq!(
+        args(&["sample.txt", "-", "input.txt"]),
+        vec![
+            InputSource::File(PathBuf::from("sample.txt")),
+            InputSource::Stdin,
+            InputSource::File(PathBuf::from("input.txt")),
+        ]
+    );
+    assert_eq!(InputSource::Stdin.to_string(), "stdin");
+    assert_eq!(
+        InputSource::File(PathBuf::from("input/05.txt")).to_string(),
+        "input/05.txt"
+    );
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format {s:?}, expected `text` or `json`")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub format: Format,
+    pub verbosity: u8,
+    pub threads: Option<usize>,
+    pub sources: Vec<InputSource>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut format = Format::default();
+        let mut verbosity = 0;
+        let mut threads = None;
+        let mut paths = vec![];
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    format = args
+                        .next()
+                        .ok_or_else(|| "--format needs a value".to_string())?
+                        .parse()?
+                }
+                "--threads" => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| "--threads needs a value".to_string())?;
+                    threads = Some(crate::parallel::parse_threads(&value)?)
+                }
+                _ if let Some(level) = crate::trace::verbosity_flag(&arg) => verbosity = level,
+                _ if arg.starts_with("--") => return Err(format!("unexpected argument {arg:?}")),
+                _ => paths.push(arg),
+            }
+        }
+
+        Ok(Self {
+            format,
+            verbosity,
+            threads,
+            sources: InputSource::from_args(paths),
+        })
+    }
+}
+
+#[test]
+fn test_we_can_parse_solver_arguments() {
+    let args = |args: &[&str]| Args::parse(args.iter().map(|arg| arg.to_string()));
+
+    assert_eq!(
+        args(&[]),
+        Ok(Args {
+            format: Format::Text,
+            verbosity: 0,
+            threads: None,
+            sources: vec![InputSource::Stdin]
+        })
+    );
+    assert_eq!(
+        args(&["--format", "json", "input.txt"]),
+        Ok(Args {
+            format: Format::Json,
+            verbosity: 0,
+            threads: None,
+            sources: vec![InputSource::File(PathBuf::from("input.txt"))]
+        })
+    );
+    assert_eq!(
+        args(&["-vv", "input.txt"]),
+        Ok(Args {
+            format: Format::Text,
+            verbosity: 2,
+            threads: None,
+            sources: vec![InputSource::File(PathBuf::from("input.txt"))]
+        })
+    );
+    assert_eq!(
+        args(&["--threads", "4", "-"]),
+        Ok(Args {
+            format: Format::Text,
+            verbosity: 0,
+            threads: Some(4),
+            sources: vec![InputSource::Stdin]
+        })
+    );
+    assert!(args(&["--format"]).is_err());
+    assert!(args(&["--format", "xml"]).is_err());
+    assert!(args(&["--verbose"]).is_err());
+    assert!(args(&["--threads"]).is_err());
+    assert!(args(&["--threads", "0"]).is_err());
+}
+
+// FNV-1a, which unlike `DefaultHasher` is stable across Rust releases, so hashes can be
+// compared between runs built by different toolchains.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[test]
+fn test_input_hashes_are_stable() {
+    assert_eq!(input_hash(""), "cbf29ce484222325");
+    assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    assert_ne!(input_hash("L68\n"), input_hash("L68\r\n"));
+}
+
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    parse_time_ns: u64,
+    solve_time_ns: u64,
+    input_hash: String,
+    input: String,
+}
+
+fn write_answer(
+    output: &mut dyn Write,
+    format: Format,
+    solver: &Solver,
+    source: &InputSource,
+    labelled: bool,
+    input: &str,
+    answer: &Answer,
+) -> std::io::Result<()> {
+    match format {
+        Format::Text if labelled => writeln!(output, "{source}: {}", answer.answer),
+        Format::Text => writeln!(output, "{}", answer.answer),
+        Format::Json => {
+            let json = JsonAnswer {
+                day: solver.day,
+                part: solver.part,
+                answer: &answer.answer,
+                parse_time_ns: answer.parse_time.as_nanos() as u64,
+                solve_time_ns: answer.solve_time.as_nanos() as u64,
+                input_hash: input_hash(input),
+                input: source.to_string(),
+            };
+            serde_json::to_writer(&mut *output, &json)?;
+            writeln!(output)
+        }
+    }
+}
+
+// Text answers are labelled with their input only when there is more than one, so a single
+// input prints just the answer, as it always has. JSON answers are one object per line.
+pub fn solve_inputs(
+    program: &str,
+    solver: &Solver,
+    sources: &[InputSource],
+    format: Format,
+    output: &mut dyn Write,
+    errors: &mut dyn Write,
+) -> std::io::Result<bool> {
+    let mut succeeded = true;
+
+    for source in sources {
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                writeln!(errors, "{program}: failed to read {source}: {e}")?;
+                succeeded = false;
+                continue;
+            }
+        };
+
+        match solver.run(&input) {
+            Ok(answer) => write_answer(
+                output,
+                format,
+                solver,
+                source,
+                sources.len() > 1,
+                &input,
+                &answer,
+            )?,
+            Err(e) => {
+                writeln!(
+                    errors,
+                    "{program}: failed to parse input from {source}: {e}"
+                )?;
+                succeeded = false;
+            }
+        }
+    }
+
+    Ok(succeeded)
+}
+
+#[test]
+fn test_we_can_solve_several_inputs_and_label_the_answers() {
+    let dir = std::env::temp_dir().join(format!("toolbox-inputs-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("sample.txt"), "a b\n").unwrap();
+    std::fs::write(dir.join("input.txt"), "a b c\n").unwrap();
+    std::fs::write(dir.join("empty.txt"), "").unwrap();
+
+    let solve = |sources: &[InputSource]| {
+        let mut output = vec![];
+        let mut errors = vec![];
+        let succeeded = solve_inputs(
+            "words",
+            &COUNT_WORDS,
+            sources,
+            Format::Text,
+            &mut output,
+            &mut errors,
+        )
+        .unwrap();
+        (
+            succeeded,
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(errors).unwrap(),
+        )
+    };
+
+    let sample = InputSource::File(dir.join("sample.txt"));
+    let input = InputSource::File(dir.join("input.txt"));
+    let empty = InputSource::File(dir.join("empty.txt"));
+    let missing = InputSource::File(dir.join("missing.txt"));
+
+    assert_eq!(
+        solve(std::slice::from_ref(&sample)),
+        (true, "2\n".to_string(), String::new())
+    );
+    assert_eq!(
+        solve(&[sample, input]),
+        (
+            true,
+            format!(
+                "{}: 2\n{}: 3\n",
+                dir.join("sample.txt").display(),
+                dir.join("input.txt").display()
+            ),
+            String::new()
+        )
+    );
+
+    let (succeeded, output, errors) = solve(&[empty, missing]);
+    assert!(!succeeded);
+    assert_eq!(output, "");
+    assert!(errors.contains("words: failed to parse input from "));
+    assert!(errors.contains("empty.txt: no words"));
+    assert!(errors.contains("words: failed to read "));
+    assert!(errors.contains("missing.txt"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(test)]
+const COUNT_WORDS: Solver = Solver {
+    day: 7,
+    part: 1,
+    parse: |input| match input.split_whitespace().count() {
+        0 => Err("no words".to_string()),
+        count => Ok(Box::new(count)),
+    },
+    solve: |count| Ok(count.downcast_ref::<usize>().unwrap().to_string()),
+    generate: |_, _| None,
+    summarize: |_| vec![],
+    fuzz_targets: &[],
+};
+
+#[test]
+fn test_we_can_write_answers_as_json() {
+    let dir = std::env::temp_dir().join(format!("toolbox-inputs-json-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("sample.txt"), "a \"b\"\n").unwrap();
+
+    let mut output = vec![];
+    let succeeded = solve_inputs(
+        "words",
+        &COUNT_WORDS,
+        &[InputSource::File(dir.join("sample.txt"))],
+        Format::Json,
+        &mut output,
+        &mut vec![],
+    )
+    .unwrap();
+    assert!(succeeded);
+    let output = String::from_utf8(output).unwrap();
+    assert_eq!(output.lines().count(), 1);
+    let json = serde_json::from_str::<serde_json::Value>(&output).unwrap();
+    assert_eq!(json["day"], 7);
+    assert_eq!(json["part"], 1);
+    assert_eq!(json["answer"], "2");
+    assert!(json["parse_time_ns"].is_u64());
+    assert!(json["solve_time_ns"].is_u64());
+    assert_eq!(json["input_hash"], input_hash("a \"b\"\n"));
+    assert_eq!(json["input"], dir.join("sample.txt").display().to_string());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+fn program_name() -> String {
+    std::env::args()
+        .next()
+        .map(PathBuf::from)
+        .and_then(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .unwrap_or_default()
+}
+
+fn open(source: &InputSource) -> std::io::Result<Box<dyn BufRead>> {
+    Ok(match source {
+        InputSource::Stdin => Box::new(std::io::stdin().lock()),
+        InputSource::File(path) => Box::new(BufReader::new(std::fs::File::open(path)?)),
+    })
+}
+
+// Like `solve_inputs` for text answers, but reads each input a line at a time through `stream`
+// rather than all at once.
+pub fn stream_inputs<T: Display>(
+    program: &str,
+    stream: impl Fn(&mut dyn BufRead) -> Result<T, LineError>,
+    sources: &[InputSource],
+    output: &mut dyn Write,
+    errors: &mut dyn Write,
+) -> std::io::Result<bool> {
+    let mut succeeded = true;
+
+    for source in sources {
+        let mut input = match open(source) {
+            Ok(input) => input,
+            Err(e) => {
+                writeln!(errors, "{program}: failed to read {source}: {e}")?;
+                succeeded = false;
+                continue;
+            }
+        };
+
+        match stream(&mut input) {
+            Ok(answer) if sources.len() > 1 => writeln!(output, "{source}: {answer}")?,
+            Ok(answer) => writeln!(output, "{answer}")?,
+            Err(e @ LineError::Read { .. }) => {
+                writeln!(errors, "{program}: failed to read {source}: {e}")?;
+                succeeded = false;
+            }
+            Err(LineError::Parse(e)) => {
+                writeln!(
+                    errors,
+                    "{program}: failed to parse input from {source}: {e}"
+                )?;
+                succeeded = false;
+            }
+        }
+    }
+
+    Ok(succeeded)
+}
+
+#[test]
+fn test_we_can_stream_several_inputs() {
+    let dir = std::env::temp_dir().join(format!("toolbox-inputs-stream-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("sample.txt"), "1\n2\n").unwrap();
+    std::fs::write(dir.join("bad.txt"), "1\nx\n").unwrap();
+
+    let sum = |input: &mut dyn BufRead| {
+        crate::LineReader::new(input).stream(str::parse::<u32>, |numbers| numbers.sum::<u32>())
+    };
+    let solve = |sources: &[InputSource]| {
+        let mut output = vec![];
+        let mut errors = vec![];
+        let succeeded = stream_inputs("sum", sum, sources, &mut output, &mut errors).unwrap();
+        (
+            succeeded,
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(errors).unwrap(),
+        )
+    };
+
+    let sample = InputSource::File(dir.join("sample.txt"));
+    assert_eq!(
+        solve(std::slice::from_ref(&sample)),
+        (true, "3\n".to_string(), String::new())
+    );
+
+    let (succeeded, output, errors) = solve(&[
+        sample,
+        InputSource::File(dir.join("bad.txt")),
+        InputSource::File(dir.join("missing.txt")),
+    ]);
+    assert!(!succeeded);
+    assert_eq!(output, format!("{}: 3\n", dir.join("sample.txt").display()));
+    assert!(errors.contains("bad.txt: line 2: expected a number, found \"x\"\n"));
+    assert!(errors.contains("sum: failed to read "));
+    assert!(errors.contains("missing.txt"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+fn main_with<S: Solution>(
+    part: u8,
+    solve: impl FnOnce(&str, &Solver, &Args) -> std::io::Result<bool>,
+) -> ExitCode {
+    let program = program_name();
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!(
+                "{program}: {message}\nusage: {program} [-v|-vv] [--threads <n>] [--format text|json] [<path>|-]..."
+            );
+            return ExitCode::from(2);
+        }
+    };
+    crate::trace::set_verbosity(args.verbosity);
+    if let Some(threads) = args.threads {
+        crate::parallel::set_threads(threads);
+    }
+    let solver = crate::solvers::<S>()[part as usize - 1];
+    match solve(&program, &solver, &args) {
+        Ok(true) => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+pub fn solution_main<S: Solution>(part: u8) -> ExitCode {
+    main_with::<S>(part, |program, solver, args| {
+        solve_inputs(
+            program,
+            solver,
+            &args.sources,
+            args.format,
+            &mut std::io::stdout().lock(),
+            &mut std::io::stderr().lock(),
+        )
+    })
+}
+
+// JSON answers include the hash of the whole input, so they are still solved from a string.
+pub fn streaming_main<S: Solution, T: Display>(
+    part: u8,
+    stream: impl Fn(&mut dyn BufRead) -> Result<T, LineError>,
+) -> ExitCode {
+    main_with::<S>(part, |program, solver, args| match args.format {
+        Format::Text => stream_inputs(
+            program,
+            stream,
+            &args.sources,
+            &mut std::io::stdout().lock(),
+            &mut std::io::stderr().lock(),
+        ),
+        Format::Json => solve_inputs(
+            program,
+            solver,
+            &args.sources,
+            args.format,
+            &mut std::io::stdout().lock(),
+            &mut std::io::stderr().lock(),
+        ),
+    })
+}
@@ -0,0 +1,266 @@
+use std::ops::RangeInclusive;
+
+pub trait Step: Copy + Ord {
+    fn successor(self) -> Option<Self>;
+
+    fn predecessor(self) -> Option<Self>;
+
+    // How many values `start..=end` holds, which is one more than `Self` can count when the
+    // range covers the whole type.
+    fn count_inclusive(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_step {
+    ( $($t:ty),* ) => {
+        $(
+            impl Step for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count_inclusive(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_step!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+// Intervals are kept sorted, disjoint and not touching, so each value is in at most one of
+// them and adjacent insertions coalesce.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { intervals: vec![] }
+    }
+}
+
+impl<T: Step> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|&(_, b)| b.successor().is_some_and(|next| next < start));
+        let last = self
+            .intervals
+            .partition_point(|&(a, _)| end.successor().is_none_or(|next| a <= next));
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|&(_, b)| b < value);
+        self.intervals.get(index).is_some_and(|&(a, _)| a <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn len(&self) -> u128 {
+        self.intervals
+            .iter()
+            .map(|&(a, b)| T::count_inclusive(a, b))
+            .sum()
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.intervals.iter().map(|&(a, b)| a..=b)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = T> {
+        self.intervals.iter().flat_map(|&(a, b)| {
+            std::iter::successors(Some(a), move |value| {
+                value.successor().filter(|&next| next <= b)
+            })
+        })
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.ranges());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        let (mut mine, mut theirs) = (self.intervals.iter(), other.intervals.iter());
+        let (mut a, mut b) = (mine.next(), theirs.next());
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) = (a, b) {
+            intersection.insert(a_start.max(b_start)..=a_end.min(b_end));
+            if a_end < b_end {
+                a = mine.next();
+            } else {
+                b = theirs.next();
+            }
+        }
+        intersection
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Self::new();
+        for &(start, end) in &self.intervals {
+            let mut remaining = Some(start);
+            for &(a, b) in other
+                .intervals
+                .iter()
+                .filter(|&&(a, b)| b >= start && a <= end)
+            {
+                let Some(from) = remaining else { break };
+                if let Some(before) = a.predecessor().filter(|&before| before >= from) {
+                    difference.insert(from..=before);
+                }
+                remaining = b.successor();
+            }
+            if let Some(from) = remaining.filter(|&from| from <= end) {
+                difference.insert(from..=end);
+            }
+        }
+        difference
+    }
+}
+
+impl<T: Step> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Step> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+fn set(ranges: &[RangeInclusive<u64>]) -> IntervalSet<u64> {
+    ranges.iter().cloned().collect()
+}
+
+#[test]
+fn test_inserting_coalesces_overlapping_and_adjacent_intervals() {
+    assert_eq!(
+        set(&[3..=5, 10..=14, 16..=20, 12..=18]),
+        set(&[3..=5, 10..=20])
+    );
+    assert_eq!(set(&[1..=2, 3..=4]).ranges().collect::<Vec<_>>(), [1..=4]);
+    assert_eq!(set(&[5..=6, 1..=2, 3..=4]), set(&[1..=6]));
+    assert_eq!(set(&[5..=6, 1..=2, 3..=3]), set(&[1..=3, 5..=6]));
+    assert_eq!(
+        set(&[20..=30, 1..=2, 10..=12]).ranges().collect::<Vec<_>>(),
+        [1..=2, 10..=12, 20..=30]
+    );
+    assert_eq!(set(&[1..=100, 40..=50]), set(&[1..=100]));
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = set(&[5..=3]);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_we_can_ask_whether_a_value_is_in_the_set() {
+    let fresh = set(&[3..=5, 10..=14, 16..=20, 12..=18]);
+    assert_eq!(
+        [1, 5, 8, 11, 17, 32].map(|id| fresh.contains(id)),
+        [false, true, false, true, true, false]
+    );
+    assert!(!IntervalSet::<u64>::new().contains(0));
+}
+
+#[test]
+fn test_the_length_of_a_set_cannot_overflow() {
+    assert_eq!(set(&[3..=5, 10..=14, 16..=20, 12..=18]).len(), 14);
+    assert_eq!(IntervalSet::<u64>::new().len(), 0);
+    assert_eq!(set(&[0..=u64::MAX]).len(), u64::MAX as u128 + 1);
+    assert_eq!(
+        [i8::MIN..=-1, 0..=i8::MAX]
+            .into_iter()
+            .collect::<IntervalSet<i8>>()
+            .len(),
+        256
+    );
+}
+
+#[test]
+fn test_we_can_iterate_over_the_values_in_a_set() {
+    assert_eq!(
+        set(&[8..=9, 1..=3]).iter().collect::<Vec<_>>(),
+        [1, 2, 3, 8, 9]
+    );
+    assert_eq!(
+        [250..=255_u8]
+            .into_iter()
+            .collect::<IntervalSet<u8>>()
+            .iter()
+            .count(),
+        6
+    );
+}
+
+#[test]
+fn test_we_can_combine_sets() {
+    let a = set(&[1..=10, 20..=30]);
+    let b = set(&[5..=25, 40..=50]);
+
+    assert_eq!(a.union(&b), set(&[1..=30, 40..=50]));
+    assert_eq!(a.intersection(&b), set(&[5..=10, 20..=25]));
+    assert_eq!(a.difference(&b), set(&[1..=4, 26..=30]));
+    assert_eq!(b.difference(&a), set(&[11..=19, 40..=50]));
+    assert_eq!(a.difference(&a), IntervalSet::new());
+    assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+    assert_eq!(
+        set(&[0..=u64::MAX]).difference(&set(&[0..=9])),
+        set(&[10..=u64::MAX])
+    );
+    assert_eq!(
+        set(&[0..=u64::MAX]).difference(&set(&[5..=u64::MAX])),
+        set(&[0..=4])
+    );
+}
+
+#[test]
+fn test_sets_agree_with_a_set_of_every_value() {
+    use crate::random;
+    use std::collections::BTreeSet;
+    let every_value =
+        |ranges: &[RangeInclusive<u64>]| ranges.iter().cloned().flatten().collect::<BTreeSet<_>>();
+    random::check(2000, |rng| {
+        let (a, b) = (
+            random::range_list(rng, 6, 60),
+            random::range_list(rng, 6, 60),
+        );
+        let (set_a, set_b) = (set(&a), set(&b));
+        let (values_a, values_b) = (every_value(&a), every_value(&b));
+
+        assert_eq!(set_a.iter().collect::<BTreeSet<_>>(), values_a);
+        assert_eq!(set_a.len(), values_a.len() as u128);
+        assert!((0..=61).all(|value| set_a.contains(value) == values_a.contains(&value)));
+        let values = |set: IntervalSet<u64>| set.iter().collect::<BTreeSet<_>>();
+        assert_eq!(values(set_a.union(&set_b)), &values_a | &values_b);
+        assert_eq!(values(set_a.intersection(&set_b)), &values_a & &values_b);
+        assert_eq!(values(set_a.difference(&set_b)), &values_a - &values_b);
+    });
+}
@@ -1,5 +1,60 @@
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod dashboard;
+pub mod fuzz;
+pub mod graph;
+pub mod grid;
+pub mod input_store;
+mod inputs;
+mod interval_set;
+mod line_reader;
+pub mod memo;
+pub mod parallel;
+pub mod parse;
+mod parse_error;
+mod process;
+pub mod random;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod snapshot;
+mod solution;
+mod solver;
+mod test_cases;
+pub mod trace;
+pub mod verify;
+
+pub use graph::Graph;
+pub use grid::Grid;
+pub use grid::Position;
+pub use inputs::Args;
+pub use inputs::Format;
+pub use inputs::InputSource;
+pub use inputs::input_hash;
+pub use inputs::solution_main;
+pub use inputs::solve_inputs;
+pub use inputs::stream_inputs;
+pub use inputs::streaming_main;
+pub use interval_set::IntervalSet;
+pub use interval_set::Step;
+pub use line_reader::LineError;
+pub use line_reader::LineReader;
+pub use parse_error::ParseError;
+pub use parse_error::parse_lines;
+pub use parse_error::parse_lines_from;
+pub use process::ProcessOutput;
+pub use process::execute;
+pub use solution::Solution;
+pub use solution::solve;
+pub use solution::solvers;
+pub use solver::Answer;
+pub use solver::Parsed;
+pub use solver::Registry;
+pub use solver::Solver;
+pub use test_cases::TestCase;
+pub use test_cases::load_test_cases;
+
 use std::io::Read;
-use std::io::Write;
 
 #[macro_export]
 macro_rules! binary_path {
@@ -9,28 +64,14 @@ macro_rules! binary_path {
 }
 
 pub fn invoke_executable(path: &str, input: &str) -> String {
-    let mut child = std::process::Command::new(path)
-        .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::piped())
-        .spawn()
-        .unwrap_or_else(|_| panic!("failed to run {}", path));
-
-    let mut child_stdin = child.stdin.take().unwrap();
-    let mut child_stdout = child.stdout.take().unwrap();
-
-    write!(&mut child_stdin, "{input}").expect("failed to write to child");
-
-    drop(child_stdin);
-
-    let mut child_output = String::new();
-    child_stdout
-        .read_to_string(&mut child_output)
-        .expect("failed to read child output");
-
-    let res = child.wait().expect("failed to wait for child");
-    assert!(res.success());
+    invoke_executable_with_args(path, &[], input)
+}
 
-    child_output
+pub fn invoke_executable_with_args(path: &str, args: &[&str], input: &str) -> String {
+    let output =
+        execute(path, args, input, None).unwrap_or_else(|e| panic!("failed to run {}: {e}", path));
+    output.assert_success();
+    output.stdout
 }
 
 pub fn read_to_string(input: &mut dyn Read) -> Result<String, std::io::Error> {
@@ -50,7 +91,7 @@ fn test_we_can_read_to_a_string() {
     struct ReadFailer;
     impl Read for ReadFailer {
         fn read(&mut self, _: &mut [u8]) -> Result<usize, std::io::Error> {
-            Err(std::io::Error::new(std::io::ErrorKind::Other, ""))
+            Err(std::io::Error::other(""))
         }
     }
 
@@ -59,4 +100,4 @@ fn test_we_can_read_to_a_string() {
 
 pub fn read_stdin_to_string() -> String {
     read_to_string(&mut std::io::stdin().lock()).unwrap()
-}
\ No newline at end of file
+}
@@ -0,0 +1,165 @@
+use crate::ParseError;
+use std::fmt::Display;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum LineError {
+    Read { line: usize, error: std::io::Error },
+    Parse(ParseError),
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineError::Read { line, error } => write!(f, "line {line}: failed to read: {error}"),
+            LineError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for LineError {}
+
+impl From<ParseError> for LineError {
+    fn from(e: ParseError) -> Self {
+        LineError::Parse(e)
+    }
+}
+
+// Reads one line at a time into a buffer it reuses, so an input of any length streams through in
+// the memory of its longest line.
+pub struct LineReader<R> {
+    reader: R,
+    buffer: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> LineReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            line_number: 0,
+        }
+    }
+
+    // The next line and its number, counting from 1, without its `\n` or `\r\n`. The line
+    // borrows the reader's buffer, so it only lasts until the next call.
+    pub fn next_line(&mut self) -> Option<Result<(usize, &str), LineError>> {
+        self.buffer.clear();
+        match self.reader.read_line(&mut self.buffer) {
+            Ok(0) => None,
+            Ok(_) => {
+                self.line_number += 1;
+                let line = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                Some(Ok((self.line_number, line)))
+            }
+            Err(error) => Some(Err(LineError::Read {
+                line: self.line_number + 1,
+                error,
+            })),
+        }
+    }
+
+    pub fn try_fold<T, E: Into<ParseError>>(
+        mut self,
+        init: T,
+        mut f: impl FnMut(T, &str) -> Result<T, E>,
+    ) -> Result<T, LineError> {
+        let mut accumulator = init;
+        while let Some(line) = self.next_line() {
+            let (line_number, line) = line?;
+            accumulator = f(accumulator, line)
+                .map_err(|e| e.into().with_line(line_number).with_text(line))?;
+        }
+        Ok(accumulator)
+    }
+
+    // Parses each line and hands the values to `consume` as they are read, stopping at the
+    // first line that fails.
+    pub fn stream<T, E: Into<ParseError>, U>(
+        mut self,
+        mut parse: impl FnMut(&str) -> Result<T, E>,
+        consume: impl FnOnce(&mut dyn Iterator<Item = T>) -> U,
+    ) -> Result<U, LineError> {
+        let mut error = None;
+        let mut values = std::iter::from_fn(|| {
+            let result = match self.next_line()? {
+                Ok((line_number, line)) => parse(line)
+                    .map_err(|e| LineError::Parse(e.into().with_line(line_number).with_text(line))),
+                Err(e) => Err(e),
+            };
+            result.map_err(|e| error = Some(e)).ok()
+        });
+        let consumed = consume(&mut values);
+        match error {
+            Some(e) => Err(e),
+            None => Ok(consumed),
+        }
+    }
+}
+
+#[cfg(test)]
+fn read_all(input: &[u8]) -> Vec<(usize, String)> {
+    let mut reader = LineReader::new(input);
+    let mut lines = vec![];
+    while let Some(line) = reader.next_line() {
+        let (line_number, line) = line.unwrap();
+        lines.push((line_number, line.to_string()));
+    }
+    lines
+}
+
+#[test]
+fn test_lines_are_numbered_without_their_line_endings() {
+    let expected = vec![
+        (1, "a".to_string()),
+        (2, "".to_string()),
+        (3, "b".to_string()),
+    ];
+    assert_eq!(read_all(b"a\n\nb\n"), expected);
+    assert_eq!(read_all(b"a\r\n\r\nb\r\n"), expected);
+    assert_eq!(read_all(b"a\n\nb"), expected);
+    assert_eq!(read_all(b""), vec![]);
+    assert_eq!(read_all(b"a\rb\n"), vec![(1, "a\rb".to_string())]);
+}
+
+#[test]
+fn test_a_line_that_is_not_utf8_is_a_read_error_on_that_line() {
+    let mut reader = LineReader::new(&b"a\n\xff\n"[..]);
+    assert!(reader.next_line().unwrap().is_ok());
+    assert!(matches!(
+        reader.next_line(),
+        Some(Err(LineError::Read { line: 2, .. }))
+    ));
+}
+
+#[test]
+fn test_parse_errors_name_the_line_they_came_from() {
+    let sum = |input: &[u8]| {
+        LineReader::new(input).try_fold(0, |sum, line| line.parse::<u32>().map(|n| sum + n))
+    };
+    assert_eq!(sum(b"1\r\n2\r\n3").unwrap(), 6);
+    assert_eq!(
+        sum(b"1\n2\nx\n4\n").unwrap_err().to_string(),
+        "line 3: expected a number, found \"x\""
+    );
+}
+
+#[test]
+fn test_we_can_stream_parsed_lines() {
+    let total = |input: &[u8]| {
+        LineReader::new(input).stream(str::parse::<u32>, |numbers| numbers.sum::<u32>())
+    };
+    assert_eq!(total(b"1\n2\n3\n").unwrap(), 6);
+    assert_eq!(total(b"").unwrap(), 0);
+    assert_eq!(
+        total(b"1\n2\n\n4\n").unwrap_err().to_string(),
+        "line 3: expected a number, found \"\""
+    );
+
+    let mut seen = vec![];
+    let _ = LineReader::new(&b"1\nx\n3\n"[..])
+        .stream(str::parse::<u32>, |numbers| seen.extend(numbers));
+    assert_eq!(seen, [1]);
+}
@@ -0,0 +1,153 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    capacity: Option<usize>,
+    order: VecDeque<K>,
+    stats: MemoStats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            capacity: None,
+            order: VecDeque::new(),
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity_limit(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        self.try_get_or_insert_with(key, |memo| Ok::<_, std::convert::Infallible>(compute(memo)))
+            .unwrap_or_else(|never| match never {})
+    }
+
+    // Failures are not cached.
+    pub fn try_get_or_insert_with<E>(
+        &mut self,
+        key: K,
+        compute: impl FnOnce(&mut Self) -> Result<V, E>,
+    ) -> Result<V, E> {
+        if let Some(value) = self.values.get(&key) {
+            self.stats.hits += 1;
+            return Ok(value.clone());
+        }
+        self.stats.misses += 1;
+        let value = compute(self)?;
+        self.insert(key, value.clone());
+        Ok(value)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        let Some(capacity) = self.capacity else {
+            self.values.insert(key, value);
+            return;
+        };
+        if capacity == 0 {
+            return;
+        }
+        while self.values.len() >= capacity {
+            let oldest = self
+                .order
+                .pop_front()
+                .expect("every cached key is in order");
+            self.values.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+        self.order.push_back(key.clone());
+        self.values.insert(key, value);
+    }
+}
+
+#[cfg(test)]
+fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+    memo.get_or_insert_with(n, |memo| match n {
+        0 | 1 => n,
+        _ => fibonacci(n - 1, memo) + fibonacci(n - 2, memo),
+    })
+}
+
+#[test]
+fn test_a_memo_computes_each_value_once() {
+    let mut memo = Memo::new();
+    assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+    assert_eq!(memo.len(), 91);
+    assert_eq!(
+        memo.stats(),
+        MemoStats {
+            hits: 88,
+            misses: 91,
+            evictions: 0
+        }
+    );
+    assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+    assert_eq!(memo.stats().hits, 89);
+}
+
+#[test]
+fn test_a_memo_with_a_capacity_forgets_its_oldest_values() {
+    let mut memo = Memo::with_capacity_limit(3);
+    assert_eq!(fibonacci(30, &mut memo), 832_040);
+    assert_eq!(memo.len(), 3);
+    assert_eq!(memo.stats().misses, 31);
+    assert_eq!(memo.stats().evictions, 31 - 3);
+}
+
+#[test]
+#[should_panic(expected = "0 is not cached")]
+fn test_a_memo_recomputes_a_forgotten_value() {
+    let mut memo = Memo::with_capacity_limit(3);
+    fibonacci(30, &mut memo);
+    memo.get_or_insert_with(0, |_| panic!("0 is not cached"));
+}
+
+#[test]
+fn test_a_memo_does_not_cache_failures() {
+    let mut memo = Memo::<u8, u8>::new();
+    assert_eq!(memo.try_get_or_insert_with(1, |_| Err("no")), Err("no"));
+    assert!(memo.is_empty());
+    assert_eq!(memo.try_get_or_insert_with(1, |_| Ok::<_, &str>(2)), Ok(2));
+    assert_eq!(memo.try_get_or_insert_with(1, |_| Err("no")), Ok(2));
+
+    let mut memo = Memo::<u8, u8>::with_capacity_limit(0);
+    assert_eq!(memo.get_or_insert_with(1, |_| 2), 2);
+    assert!(memo.is_empty());
+}
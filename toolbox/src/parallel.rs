@@ -0,0 +1,155 @@
+use std::cell::Cell;
+use std::iter::Sum;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static OVERRIDE: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    OVERRIDE
+        .get()
+        .or_else(|| Some(THREADS.load(Ordering::Relaxed)).filter(|&threads| threads > 0))
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, usize::from))
+}
+
+pub fn parse_threads(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .ok()
+        .filter(|&threads| threads > 0)
+        .ok_or_else(|| format!("--threads needs a positive number, found {value:?}"))
+}
+
+#[test]
+fn test_we_can_parse_a_thread_count() {
+    assert_eq!(parse_threads("4"), Ok(4));
+    assert!(parse_threads("0").is_err());
+    assert!(parse_threads("many").is_err());
+}
+
+pub fn with_threads<T>(threads: usize, f: impl FnOnce() -> T) -> T {
+    let outer = OVERRIDE.replace(Some(threads));
+    let result = f();
+    OVERRIDE.set(outer);
+    result
+}
+
+fn chunks<T: Sync, U: Send>(items: &[T], f: impl Fn(&[T]) -> U + Sync) -> Vec<U> {
+    let threads = threads().min(items.len());
+    if threads <= 1 {
+        return vec![f(items)];
+    }
+    let chunk_size = items.len().div_ceil(threads);
+    let (outer_threads, capture) = (OVERRIDE.get(), crate::trace::current_capture());
+    std::thread::scope(|scope| {
+        let f = &f;
+        let handles = items
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let capture = capture.clone();
+                scope.spawn(move || {
+                    OVERRIDE.set(outer_threads);
+                    crate::trace::with_capture(capture, || f(chunk))
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect()
+    })
+}
+
+pub fn map<T: Sync, U: Send>(items: &[T], f: impl Fn(&T) -> U + Sync) -> Vec<U> {
+    chunks(items, |chunk| chunk.iter().map(&f).collect::<Vec<_>>())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+pub fn sum<T: Sync, U: Send + Sum>(items: &[T], f: impl Fn(&T) -> U + Sync) -> U {
+    chunks(items, |chunk| chunk.iter().map(&f).sum::<U>())
+        .into_iter()
+        .sum()
+}
+
+#[test]
+fn test_parallel_results_match_sequential_ones() {
+    let numbers = (1..=1000_u64).collect::<Vec<_>>();
+    for threads in [1, 2, 3, 8, 2000] {
+        with_threads(threads, || {
+            assert_eq!(
+                map(&numbers, |n| n * n),
+                numbers.iter().map(|n| n * n).collect::<Vec<_>>()
+            );
+            assert_eq!(sum(&numbers, |n| n * n), 333_833_500);
+            assert_eq!(map(&[] as &[u64], |n| n + 1), Vec::<u64>::new());
+            assert_eq!(sum(&[] as &[u64], |n| n + 1), 0);
+        });
+    }
+}
+
+#[test]
+fn test_sums_are_the_same_on_any_number_of_threads() {
+    crate::random::check(50, |rng| {
+        let numbers = (0..rng.below(200))
+            .map(|_| rng.range(0..=1_000_000))
+            .collect::<Vec<u64>>();
+        let sequential = with_threads(1, || sum(&numbers, |n| n * 3));
+        assert_eq!(sequential, numbers.iter().map(|n| n * 3).sum::<u64>());
+        assert_eq!(with_threads(4, || sum(&numbers, |n| n * 3)), sequential);
+    });
+}
+
+#[test]
+fn test_work_is_spread_over_the_requested_threads() {
+    let numbers = (0..100).collect::<Vec<_>>();
+    let workers = |threads| {
+        let mut ids = with_threads(threads, || map(&numbers, |_| std::thread::current().id()));
+        ids.dedup();
+        ids.len()
+    };
+    assert_eq!(workers(1), 1);
+    assert_eq!(workers(4), 4);
+    assert_eq!(with_threads(3, threads), 3);
+}
+
+#[test]
+fn test_workers_run_with_the_callers_thread_count_and_capture() {
+    let numbers = (0..8).collect::<Vec<u64>>();
+    assert_eq!(with_threads(2, || map(&numbers, |_| threads())), [2; 8]);
+
+    let (total, mut events) = crate::trace::capture(1, || {
+        with_threads(4, || {
+            sum(&numbers, |&n| {
+                crate::event!(Info, "item", n = n);
+                n
+            })
+        })
+    });
+    events.sort();
+    assert_eq!(total, 28);
+    assert_eq!(
+        events,
+        (0..8)
+            .map(|n| format!("level=info event=item n={n}"))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[should_panic(expected = "bad item")]
+fn test_a_panic_in_a_worker_reaches_the_caller() {
+    with_threads(4, || map(&[1, 2, 3, 4], |&n| assert!(n != 3, "bad item")));
+}
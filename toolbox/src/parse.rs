@@ -0,0 +1,248 @@
+use crate::ParseError;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+// Each helper reports positions relative to the text it was handed and shifts the positions
+// reported by the parsers it calls, so they nest: a number inside a range inside a comma
+// separated list is reported at its own column.
+
+// Where `part`, a slice of `whole`, starts, counting columns from 1.
+fn column_of(whole: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - whole.as_ptr() as usize;
+    whole[..offset].chars().count() + 1
+}
+
+// Parses `text`, which starts at `column` of the text around it.
+pub fn located<T, E: Into<ParseError>>(
+    text: &str,
+    column: usize,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, ParseError> {
+    parse(text).map_err(|e| e.into().with_text(text).relative_to_column(column))
+}
+
+// The whitespace separated fields of a line, with the column each starts at.
+pub fn fields(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace()
+        .map(move |field| (column_of(line, field), field))
+}
+
+pub fn columns<T, E: Into<ParseError>>(
+    line: &str,
+    mut item: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>, ParseError> {
+    fields(line)
+        .map(|(column, field)| located(field, column, &mut item))
+        .collect()
+}
+
+// A trailing separator is allowed, as in `11-22,95-115,`.
+pub fn separated<T, E: Into<ParseError>>(
+    input: &str,
+    separator: char,
+    mut item: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .split_terminator(separator)
+        .map(|part| located(part, column_of(input, part), &mut item))
+        .collect()
+}
+
+// `expected` describes the whole pair, for when the separator is missing.
+pub fn pair<A, B, EA: Into<ParseError>, EB: Into<ParseError>>(
+    input: &str,
+    separator: &str,
+    expected: &str,
+    first: impl FnOnce(&str) -> Result<A, EA>,
+    second: impl FnOnce(&str) -> Result<B, EB>,
+) -> Result<(A, B), ParseError> {
+    let (left, right) = input
+        .split_once(separator)
+        .ok_or_else(|| ParseError::expected(expected).with_text(input))?;
+    Ok((
+        located(left, 1, first)?,
+        located(right, column_of(input, right), second)?,
+    ))
+}
+
+// `start-end`, with `start` no greater than `end`.
+pub fn range<T>(input: &str, expected: &str) -> Result<RangeInclusive<T>, ParseError>
+where
+    T: FromStr + Ord,
+    T::Err: Into<ParseError>,
+{
+    let (start, end) = pair(input, "-", expected, str::parse::<T>, str::parse::<T>)?;
+    if start > end {
+        return Err(ParseError::expected(expected).with_text(input));
+    }
+    Ok(start..=end)
+}
+
+// `key: value value ...`, with the values separated by whitespace.
+pub fn key_values<K, V, EK: Into<ParseError>, EV: Into<ParseError>>(
+    line: &str,
+    expected: &str,
+    key: impl FnOnce(&str) -> Result<K, EK>,
+    value: impl FnMut(&str) -> Result<V, EV>,
+) -> Result<(K, Vec<V>), ParseError> {
+    pair(line, ":", expected, key, |values| columns(values, value))
+}
+
+// Blocks of lines separated by blank lines, with the line each block starts on.
+pub fn sections(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut first_line = 1;
+    input.split("\n\n").map(move |section| {
+        let section_line = first_line;
+        first_line += section.split('\n').count() + 1;
+        (section_line, section)
+    })
+}
+
+// Two blocks separated by the first blank line; `expected` describes both, for when there is
+// no blank line.
+pub fn two_sections<A, B, EA: Into<ParseError>, EB: Into<ParseError>>(
+    input: &str,
+    expected: &str,
+    first: impl FnOnce(&str) -> Result<A, EA>,
+    second: impl FnOnce(&str) -> Result<B, EB>,
+) -> Result<(A, B), ParseError> {
+    let (top, bottom) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::expected(expected))?;
+    let bottom_line = top.split('\n').count() + 2;
+    Ok((
+        first(top).map_err(|e| e.into().relative_to_line(1))?,
+        second(bottom).map_err(|e| e.into().relative_to_line(bottom_line))?,
+    ))
+}
+
+#[test]
+fn test_fields_know_their_columns() {
+    assert_eq!(
+        fields(" 12  x 3").collect::<Vec<_>>(),
+        [(2, "12"), (6, "x"), (8, "3")]
+    );
+    assert_eq!(fields("é a").collect::<Vec<_>>(), [(1, "é"), (3, "a")]);
+    assert_eq!(fields("").count(), 0);
+}
+
+#[test]
+fn test_we_can_parse_whitespace_separated_columns() {
+    assert_eq!(columns("1  2 3", str::parse::<u8>), Ok(vec![1, 2, 3]));
+    assert_eq!(
+        columns("1  x 3", str::parse::<u8>),
+        Err(ParseError::expected("a number")
+            .with_column(4)
+            .with_text("x"))
+    );
+}
+
+#[test]
+fn test_we_can_parse_separated_lists() {
+    assert_eq!(
+        separated("1,2,3,", ',', str::parse::<u8>),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(separated("", ',', str::parse::<u8>), Ok(vec![]));
+    assert_eq!(
+        separated("1,22,x", ',', str::parse::<u8>)
+            .unwrap_err()
+            .to_string(),
+        "column 6: expected a number, found \"x\""
+    );
+}
+
+#[test]
+fn test_we_can_parse_ranges() {
+    let range = |input: &str| range::<u64>(input, "a range like `3-5`");
+    assert_eq!(range("3-5"), Ok(3..=5));
+    assert_eq!(range("5-5"), Ok(5..=5));
+    assert_eq!(
+        range("12").unwrap_err().to_string(),
+        "expected a range like `3-5`, found \"12\""
+    );
+    assert_eq!(
+        range("12-x").unwrap_err().to_string(),
+        "column 4: expected a number, found \"x\""
+    );
+    assert_eq!(
+        range("5-3").unwrap_err().to_string(),
+        "expected a range like `3-5`, found \"5-3\""
+    );
+    for malformed in ["", "-", "3-", "-5", "3--5"] {
+        assert!(range(malformed).is_err(), "{malformed:?}");
+    }
+}
+
+#[test]
+fn test_positions_nest() {
+    let ranges = |input: &str| {
+        separated(input, ',', |range| {
+            self::range::<u64>(range, "a range like `3-5`")
+        })
+    };
+    assert_eq!(ranges("1-2,30-40"), Ok(vec![1..=2, 30..=40]));
+    assert_eq!(
+        ranges("1-2,30-4x").unwrap_err().to_string(),
+        "column 8: expected a number, found \"4x\""
+    );
+    assert_eq!(
+        crate::parse_lines("1-2\n3-4,5", ranges)
+            .unwrap_err()
+            .to_string(),
+        "line 2, column 5: expected a range like `3-5`, found \"5\""
+    );
+}
+
+#[test]
+fn test_we_can_parse_keys_and_values() {
+    let line = |line: &str| {
+        key_values(
+            line,
+            "a name and numbers",
+            |key| Ok::<_, ParseError>(key.to_string()),
+            str::parse::<u8>,
+        )
+    };
+    assert_eq!(line("a: 1 2"), Ok(("a".to_string(), vec![1, 2])));
+    assert_eq!(line("a:"), Ok(("a".to_string(), vec![])));
+    assert_eq!(
+        line("a 1 2").unwrap_err().to_string(),
+        "expected a name and numbers, found \"a 1 2\""
+    );
+    assert_eq!(
+        line("abc: 1 x").unwrap_err().to_string(),
+        "column 8: expected a number, found \"x\""
+    );
+}
+
+#[test]
+fn test_we_can_split_input_into_sections() {
+    assert_eq!(
+        sections("a\nb\n\nc\n\nd\ne\n").collect::<Vec<_>>(),
+        [(1, "a\nb"), (4, "c"), (6, "d\ne\n")]
+    );
+
+    let numbers = |section: &str| crate::parse_lines(section, str::parse::<u8>);
+    let expected = "numbers, a blank line, then more numbers";
+    assert_eq!(
+        two_sections("1\n2\n\n3\n", expected, numbers, numbers),
+        Ok((vec![1, 2], vec![3]))
+    );
+    assert_eq!(
+        two_sections("1\n2\n\n3\nx\n", expected, numbers, numbers)
+            .unwrap_err()
+            .to_string(),
+        "line 5: expected a number, found \"x\""
+    );
+    assert_eq!(
+        two_sections("1\nx\n\n3\n", expected, numbers, numbers)
+            .unwrap_err()
+            .line,
+        Some(2)
+    );
+    assert_eq!(
+        two_sections("1\n", expected, numbers, numbers).unwrap_err(),
+        ParseError::expected(expected)
+    );
+}
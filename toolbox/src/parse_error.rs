@@ -0,0 +1,177 @@
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub text: Option<String>,
+    pub expected: String,
+}
+
+// Errors are built from the inside out, so each `with_` only fills in what the more specific
+// parser that raised the error did not already know.
+impl ParseError {
+    pub fn expected(expected: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            text: None,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.text.get_or_insert_with(|| text.to_string());
+        self
+    }
+
+    // For an error from a parser handed only part of a line: its column, or the start of the
+    // part if it had none, becomes a column of the whole line.
+    pub fn relative_to_column(mut self, first_column: usize) -> Self {
+        self.column = Some(first_column + self.column.map_or(0, |column| column - 1));
+        self
+    }
+
+    // Likewise for a parser handed a block of lines starting at `first_line`. A block spans
+    // lines, so an error that named none still names none.
+    pub fn relative_to_line(mut self, first_line: usize) -> Self {
+        self.line = self.line.map(|line| first_line + line - 1);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "column {column}: ")?,
+            (None, None) => {}
+        }
+        write!(f, "expected {}", self.expected)?;
+        match &self.text {
+            Some(text) => write!(f, ", found {text:?}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<std::num::ParseIntError> for ParseError {
+    fn from(_: std::num::ParseIntError) -> Self {
+        Self::expected("a number")
+    }
+}
+
+#[test]
+fn test_a_parse_error_describes_where_the_input_broke() {
+    assert_eq!(
+        ParseError::expected("a number").to_string(),
+        "expected a number"
+    );
+    assert_eq!(
+        ParseError::expected("a number")
+            .with_column(4)
+            .with_line(2)
+            .with_text("12x")
+            .to_string(),
+        "line 2, column 4: expected a number, found \"12x\""
+    );
+    assert_eq!(
+        ParseError::expected("a number")
+            .with_line(2)
+            .with_text("")
+            .to_string(),
+        "line 2: expected a number, found \"\""
+    );
+}
+
+#[test]
+fn test_the_innermost_location_wins() {
+    assert_eq!(
+        ParseError::expected("a digit")
+            .with_column(3)
+            .with_text("x")
+            .with_column(1)
+            .with_text("12x"),
+        ParseError {
+            line: None,
+            column: Some(3),
+            text: Some("x".to_string()),
+            expected: "a digit".to_string()
+        }
+    );
+}
+
+pub fn parse_lines<T, E: Into<ParseError>>(
+    input: &str,
+    parse: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>, ParseError> {
+    parse_lines_from(input, 1, parse)
+}
+
+pub fn parse_lines_from<T, E: Into<ParseError>>(
+    input: &str,
+    first_line: usize,
+    mut parse: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            parse(line).map_err(|e| e.into().with_line(first_line + index).with_text(line))
+        })
+        .collect()
+}
+
+#[test]
+fn test_we_can_parse_input_line_by_line() {
+    assert_eq!(
+        parse_lines("1\n2\n3\n", str::parse::<u8>),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(parse_lines("", str::parse::<u8>), Ok(vec![]));
+    assert_eq!(
+        parse_lines("1\nx\n3\n", str::parse::<u8>),
+        Err(ParseError {
+            line: Some(2),
+            column: None,
+            text: Some("x".to_string()),
+            expected: "a number".to_string()
+        })
+    );
+    assert_eq!(
+        parse_lines_from("1\nx\n", 10, str::parse::<u8>)
+            .unwrap_err()
+            .line,
+        Some(11)
+    );
+}
+
+#[test]
+fn test_positions_can_be_made_relative_to_the_enclosing_text() {
+    let error = ParseError::expected("a digit").with_column(3);
+    assert_eq!(error.clone().relative_to_column(10).column, Some(12));
+    assert_eq!(
+        ParseError::expected("a digit")
+            .relative_to_column(10)
+            .column,
+        Some(10)
+    );
+    assert_eq!(error.with_line(2).relative_to_line(5).line, Some(6));
+    assert_eq!(
+        ParseError::expected("a digit").relative_to_line(5).line,
+        None
+    );
+}
@@ -0,0 +1,165 @@
+use std::io::{Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub struct ProcessOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub status: Option<ExitStatus>,
+    pub elapsed: Duration,
+}
+
+impl ProcessOutput {
+    pub fn timed_out(&self) -> bool {
+        self.status.is_none()
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.status.is_some_and(|status| status.success())
+    }
+
+    pub fn exit_code(&self) -> Option<i32> {
+        self.status.and_then(|status| status.code())
+    }
+
+    pub fn assert_success(&self) -> &Self {
+        assert!(
+            self.succeeded(),
+            "expected the process to succeed but it {}\nstderr:\n{}",
+            self.describe_exit(),
+            self.stderr
+        );
+        self
+    }
+
+    pub fn assert_failure(&self) -> &Self {
+        assert!(
+            !self.timed_out() && !self.succeeded(),
+            "expected the process to fail but it {}\nstdout:\n{}",
+            self.describe_exit(),
+            self.stdout
+        );
+        self
+    }
+
+    fn describe_exit(&self) -> String {
+        match self.status {
+            None => format!("timed out after {:?}", self.elapsed),
+            Some(status) => format!("exited with {status}"),
+        }
+    }
+}
+
+fn read_in_background(
+    mut pipe: impl Read + Send + 'static,
+) -> std::thread::JoinHandle<std::io::Result<String>> {
+    std::thread::spawn(move || {
+        let mut buf = String::new();
+        pipe.read_to_string(&mut buf)?;
+        Ok(buf)
+    })
+}
+
+pub fn execute(
+    path: &str,
+    args: &[&str],
+    input: &str,
+    timeout: Option<Duration>,
+) -> std::io::Result<ProcessOutput> {
+    let start = Instant::now();
+    let mut child = Command::new(path)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let mut child_stdin = child.stdin.take().expect("stdin is piped");
+    let input = input.to_string();
+    let writer = std::thread::spawn(move || child_stdin.write_all(input.as_bytes()));
+    let stdout = read_in_background(child.stdout.take().expect("stdout is piped"));
+    let stderr = read_in_background(child.stderr.take().expect("stderr is piped"));
+
+    let status = match timeout {
+        None => Some(child.wait()?),
+        Some(timeout) => loop {
+            if let Some(status) = child.try_wait()? {
+                break Some(status);
+            }
+            if start.elapsed() >= timeout {
+                child.kill()?;
+                child.wait()?;
+                break None;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        },
+    };
+    let elapsed = start.elapsed();
+
+    // A child that exits without draining stdin leaves us with a broken pipe, which is not
+    // an error as far as the caller is concerned.
+    let _ = writer.join().expect("stdin writer panicked");
+
+    Ok(ProcessOutput {
+        stdout: stdout.join().expect("stdout reader panicked")?,
+        stderr: stderr.join().expect("stderr reader panicked")?,
+        status,
+        elapsed,
+    })
+}
+
+#[test]
+fn test_execute_captures_stdout_stderr_and_exit_status() {
+    let output = execute("sh", &["-c", "cat; echo oops >&2; exit 3"], "hello\n", None).unwrap();
+    assert_eq!(output.stdout, "hello\n");
+    assert_eq!(output.stderr, "oops\n");
+    assert_eq!(output.exit_code(), Some(3));
+    assert!(!output.succeeded());
+    assert!(!output.timed_out());
+    output.assert_failure();
+}
+
+#[test]
+fn test_execute_reports_success() {
+    let output = execute("sh", &["-c", "echo fine"], "", None).unwrap();
+    assert_eq!(output.stdout, "fine\n");
+    assert_eq!(output.stderr, "");
+    assert!(output.succeeded());
+    output.assert_success();
+}
+
+#[test]
+fn test_execute_kills_a_process_that_outlives_its_timeout() {
+    let output = execute(
+        "sh",
+        &["-c", "exec sleep 5"],
+        "",
+        Some(Duration::from_millis(50)),
+    )
+    .unwrap();
+    assert!(output.timed_out());
+    assert_eq!(output.exit_code(), None);
+    assert!(output.elapsed < Duration::from_secs(5));
+}
+
+#[test]
+fn test_execute_fails_when_the_executable_does_not_exist() {
+    assert!(execute("/does/not/exist", &[], "", None).is_err());
+}
+
+#[test]
+#[should_panic(expected = "expected the process to succeed")]
+fn test_assert_success_panics_on_failure() {
+    execute("sh", &["-c", "exit 1"], "", None)
+        .unwrap()
+        .assert_success();
+}
+
+#[test]
+#[should_panic(expected = "expected the process to fail")]
+fn test_assert_failure_panics_on_success() {
+    execute("sh", &["-c", "true"], "", None)
+        .unwrap()
+        .assert_failure();
+}
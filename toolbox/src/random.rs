@@ -0,0 +1,300 @@
+use crate::{Graph, Grid};
+use std::ops::RangeInclusive;
+use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
+
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick from an empty range");
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "cannot pick a number below 0");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+#[test]
+fn test_the_same_seed_gives_the_same_numbers() {
+    let numbers = |seed| {
+        let mut rng = Rng::new(seed);
+        (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+    };
+    assert_eq!(numbers(7), numbers(7));
+    assert_ne!(numbers(7), numbers(8));
+}
+
+#[test]
+fn test_numbers_stay_in_range() {
+    let mut rng = Rng::new(1);
+    for _ in 0..1000 {
+        assert!((3..=5).contains(&rng.range(3..=5)));
+        assert!(rng.below(4) < 4);
+    }
+    assert_eq!(rng.range(9..=9), 9);
+    rng.range(0..=u64::MAX);
+    assert!((0..100).all(|_| !rng.chance(0, 3)));
+    assert!((0..100).all(|_| rng.chance(3, 3)));
+}
+
+pub fn check(cases: u64, mut property: impl FnMut(&mut Rng)) {
+    for seed in 0..cases {
+        let mut rng = Rng::new(seed);
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(|| property(&mut rng))) {
+            eprintln!("property failed for seed {seed}");
+            resume_unwind(panic);
+        }
+    }
+}
+
+#[test]
+fn test_check_runs_every_case() {
+    let mut seeds = vec![];
+    check(3, |rng| seeds.push(rng.clone().next_u64()));
+    assert_eq!(seeds.len(), 3);
+    assert_eq!(seeds[1], Rng::new(1).next_u64());
+}
+
+#[test]
+#[should_panic(expected = "odd")]
+fn test_check_passes_a_failure_on() {
+    check(10, |rng| assert!(rng.next_u64() % 2 == 0, "odd"));
+}
+
+pub fn bank(rng: &mut Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+        .collect()
+}
+
+pub fn rotation(rng: &mut Rng, max_amount: u32) -> String {
+    let direction = rng.pick(&['L', 'R']);
+    format!("{direction}{}", rng.range(0..=max_amount.into()))
+}
+
+pub fn range_list(rng: &mut Rng, count: usize, max: u64) -> Vec<RangeInclusive<u64>> {
+    (0..count)
+        .map(|_| {
+            let (a, b) = (rng.range(0..=max), rng.range(0..=max));
+            a.min(b)..=a.max(b)
+        })
+        .collect()
+}
+
+pub fn grid<T: Clone>(rng: &mut Rng, width: usize, height: usize, cells: &[T]) -> Grid<T> {
+    Grid::from_rows((0..height).map(|_| {
+        (0..width)
+            .map(|_| rng.pick(cells).clone())
+            .collect::<Vec<_>>()
+    }))
+    .expect("every row has the same width")
+}
+
+// Edges only run from a node to a later one, so there are no cycles.
+pub fn dag(rng: &mut Rng, len: usize, edge_percent: u64) -> Graph<String> {
+    let mut graph = Graph::new();
+    let nodes = (0..len)
+        .map(|index| graph.intern(format!("n{index}")))
+        .collect::<Vec<_>>();
+    for (index, &from) in nodes.iter().enumerate() {
+        for &to in &nodes[index + 1..] {
+            if rng.chance(edge_percent, 100) {
+                graph.add_edge(from, to);
+            }
+        }
+    }
+    graph
+}
+
+#[test]
+fn test_generated_inputs_have_the_requested_shape() {
+    check(100, |rng| {
+        let bank = bank(rng, 15);
+        assert_eq!(bank.len(), 15);
+        assert!(bank.chars().all(|c| ('1'..='9').contains(&c)));
+
+        let rotation = rotation(rng, 999);
+        assert!(rotation.starts_with(['L', 'R']));
+        assert!(rotation[1..].parse::<u32>().unwrap() <= 999);
+
+        for range in range_list(rng, 5, 50) {
+            assert!(range.start() <= range.end() && *range.end() <= 50);
+        }
+
+        let grid = grid(rng, 4, 3, &['.', '@']);
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+
+        let dag = dag(rng, 8, 30);
+        assert_eq!(dag.len(), 8);
+        assert_eq!(dag.find_cycle(), None);
+    });
+}
+
+pub fn rotation_list(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| rotation(rng, 999) + "\n").collect()
+}
+
+pub fn product_id_ranges(rng: &mut Rng, size: usize) -> String {
+    let ranges = (0..size)
+        .map(|_| {
+            let start = rng.range(1..=10_000_000_000);
+            format!("{start}-{}", start + rng.range(0..=10_000))
+        })
+        .collect::<Vec<_>>();
+    ranges.join(",") + "\n"
+}
+
+pub fn banks(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| bank(rng, 100) + "\n").collect()
+}
+
+pub fn roll_grid(rng: &mut Rng, size: usize) -> String {
+    grid(rng, size, size, &['.', '@']).render(|&c| c)
+}
+
+pub fn cafeteria(rng: &mut Rng, size: usize) -> String {
+    let ranges = (0..size)
+        .map(|_| {
+            let start = rng.range(1..=1_000_000_000_000);
+            format!("{start}-{}\n", start + rng.range(0..=10_000_000_000))
+        })
+        .collect::<String>();
+    let ids = (0..size)
+        .map(|_| format!("{}\n", rng.range(1..=1_000_000_000_000)))
+        .collect::<String>();
+    format!("{ranges}\n{ids}")
+}
+
+pub fn homework(rng: &mut Rng, size: usize) -> String {
+    let operand_count = 3 + rng.below(2);
+    let mut lines = vec![vec![]; operand_count + 1];
+    for _ in 0..size {
+        let width = 1 + rng.below(4);
+        let mut lengths = (0..operand_count)
+            .map(|_| 1 + rng.below(width))
+            .collect::<Vec<_>>();
+        lengths[0] = width;
+        lengths.sort_unstable();
+        if rng.chance(1, 2) {
+            lengths.reverse();
+        }
+        let left = rng.chance(1, 2);
+        for (line, &len) in lines.iter_mut().zip(&lengths) {
+            let number = bank(rng, len);
+            line.push(match left {
+                true => format!("{number:<width$}"),
+                false => format!("{number:>width$}"),
+            });
+        }
+        lines[operand_count].push(format!("{:<width$}", rng.pick(&['+', '*'])));
+    }
+    lines.iter().map(|line| line.join(" ") + "\n").collect()
+}
+
+fn device_name(mut index: usize) -> String {
+    let mut name = vec![];
+    while index > 0 || name.len() < 3 {
+        name.push(b'a' + (index % 26) as u8);
+        index /= 26;
+    }
+    name.iter().rev().map(|&b| char::from(b)).collect()
+}
+
+// Each device feeds one or two in the next layer, which keeps every path count within a `u64`.
+pub fn reactor(rng: &mut Rng, size: usize) -> String {
+    const NAMED: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+    let layer_count = (size / 4).clamp(3, 40);
+    let width = size.div_ceil(layer_count).max(1);
+    let mut names = (0..)
+        .map(device_name)
+        .filter(|name| !NAMED.contains(&name.as_str()));
+    let mut layers = vec![vec!["svr".to_string()]];
+    layers.extend((0..layer_count).map(|_| names.by_ref().take(width).collect::<Vec<_>>()));
+    layers.push(vec!["out".to_string()]);
+
+    let mut spots = (1..=layer_count)
+        .flat_map(|layer| (0..width).map(move |index| (layer, index)))
+        .collect::<Vec<_>>();
+    for name in ["you", "fft", "dac"] {
+        let (layer, index) = spots.swap_remove(rng.below(spots.len()));
+        layers[layer][index] = name.to_string();
+    }
+
+    let mut lines = String::new();
+    for pair in layers.windows(2) {
+        let (devices, next) = (&pair[0], &pair[1]);
+        for device in devices {
+            let first = rng.below(next.len());
+            let mut outputs = vec![next[first].as_str()];
+            let second = rng.below(next.len());
+            if second != first && rng.chance(1, 2) {
+                outputs.push(next[second].as_str());
+            }
+            lines += &format!("{device}: {}\n", outputs.join(" "));
+        }
+    }
+    lines
+}
+
+#[test]
+fn test_device_names_are_unique() {
+    assert_eq!(device_name(0), "aaa");
+    assert_eq!(device_name(27), "abb");
+    assert_eq!(device_name(26 * 26 * 26), "baaa");
+    let names = (0..20_000)
+        .map(device_name)
+        .collect::<std::collections::HashSet<_>>();
+    assert_eq!(names.len(), 20_000);
+}
+
+#[test]
+fn test_whole_inputs_scale_with_their_size() {
+    let mut rng = Rng::new(0);
+    assert_eq!(rotation_list(&mut rng, 7).lines().count(), 7);
+    assert_eq!(product_id_ranges(&mut rng, 7).split(',').count(), 7);
+    assert_eq!(banks(&mut rng, 7).lines().count(), 7);
+    let grid = roll_grid(&mut rng, 7);
+    assert_eq!(grid.lines().count(), 7);
+    assert!(grid.lines().all(|line| line.len() == 7));
+    assert_eq!(cafeteria(&mut rng, 7).lines().count(), 15);
+    let homework = homework(&mut rng, 7);
+    assert_eq!(
+        homework.lines().last().unwrap().split_whitespace().count(),
+        7
+    );
+    let lengths = homework.lines().map(str::len).collect::<Vec<_>>();
+    assert!(lengths.iter().all(|&len| len == lengths[0]), "{homework}");
+    let reactor = reactor(&mut rng, 100);
+    for name in ["svr:", "you:", "fft:", "dac:", " out"] {
+        assert!(reactor.contains(name), "{reactor}");
+    }
+}
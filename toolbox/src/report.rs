@@ -0,0 +1,263 @@
+use crate::Registry;
+use crate::input_store::InputStore;
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, PartialEq)]
+pub struct Phases {
+    pub read: Duration,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Phases {
+    pub fn total(&self) -> Duration {
+        self.read + self.parse + self.solve
+    }
+}
+
+#[derive(Debug)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub phases: Result<Phases, String>,
+}
+
+pub fn measure(registry: &Registry, store: &InputStore, variant: Option<&str>) -> Vec<Measurement> {
+    registry
+        .iter()
+        .map(|solver| {
+            let start = Instant::now();
+            let phases = store
+                .read(solver.day, variant)
+                .map_err(|e| e.to_string())
+                .and_then(|input| {
+                    let read = start.elapsed();
+                    let answer = solver
+                        .run(&input)
+                        .map_err(|message| format!("failed to parse input: {message}"))?;
+                    Ok(Phases {
+                        read,
+                        parse: answer.parse_time,
+                        solve: answer.solve_time,
+                    })
+                });
+            Measurement {
+                day: solver.day,
+                part: solver.part,
+                phases,
+            }
+        })
+        .collect()
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.1?}")
+}
+
+pub fn write_table(measurements: &[Measurement], output: &mut dyn Write) -> std::io::Result<()> {
+    let year_total = measurements
+        .iter()
+        .filter_map(|m| m.phases.as_ref().ok())
+        .map(Phases::total)
+        .sum::<Duration>();
+    let share = |duration: Duration| match year_total.as_secs_f64() {
+        0.0 => 0.0,
+        total => 100.0 * duration.as_secs_f64() / total,
+    };
+
+    writeln!(
+        output,
+        "{:<3}  {:<4}  {:>10}  {:>10}  {:>10}  {:>10}  {:>6}",
+        "Day", "Part", "Read", "Parse", "Solve", "Total", "Share"
+    )?;
+    for measurement in measurements {
+        match &measurement.phases {
+            Ok(phases) => writeln!(
+                output,
+                "{:<3}  {:<4}  {:>10}  {:>10}  {:>10}  {:>10}  {:>5.1}%",
+                format!("{:02}", measurement.day),
+                measurement.part,
+                format_duration(phases.read),
+                format_duration(phases.parse),
+                format_duration(phases.solve),
+                format_duration(phases.total()),
+                share(phases.total())
+            )?,
+            Err(message) => writeln!(
+                output,
+                "{:<3}  {:<4}  {message}",
+                format!("{:02}", measurement.day),
+                measurement.part
+            )?,
+        }
+    }
+    writeln!(
+        output,
+        "{:<9}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Total",
+        "",
+        "",
+        "",
+        format_duration(year_total)
+    )?;
+
+    let slowest = measurements
+        .iter()
+        .filter_map(|m| m.phases.as_ref().ok().map(|phases| (m, phases.total())))
+        .max_by_key(|&(_, total)| total);
+    if let Some((measurement, total)) = slowest {
+        writeln!(
+            output,
+            "Slowest: day {:02} part {} ({:.1}% of the year)",
+            measurement.day,
+            measurement.part,
+            share(total)
+        )?;
+    }
+    Ok(())
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn write_csv(measurements: &[Measurement], output: &mut dyn Write) -> std::io::Result<()> {
+    writeln!(output, "day,part,read_ns,parse_ns,solve_ns,total_ns,error")?;
+    for measurement in measurements {
+        match &measurement.phases {
+            Ok(phases) => writeln!(
+                output,
+                "{},{},{},{},{},{},",
+                measurement.day,
+                measurement.part,
+                phases.read.as_nanos(),
+                phases.parse.as_nanos(),
+                phases.solve.as_nanos(),
+                phases.total().as_nanos()
+            )?,
+            Err(message) => writeln!(
+                output,
+                "{},{},,,,,{}",
+                measurement.day,
+                measurement.part,
+                csv_field(message)
+            )?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+fn test_measurements() -> Vec<Measurement> {
+    vec![
+        Measurement {
+            day: 1,
+            part: 1,
+            phases: Ok(Phases {
+                read: Duration::from_micros(10),
+                parse: Duration::from_micros(20),
+                solve: Duration::from_micros(70),
+            }),
+        },
+        Measurement {
+            day: 4,
+            part: 2,
+            phases: Ok(Phases {
+                read: Duration::from_micros(10),
+                parse: Duration::from_micros(90),
+                solve: Duration::from_micros(300),
+            }),
+        },
+        Measurement {
+            day: 5,
+            part: 1,
+            phases: Err("no input for day 5, sadly".to_string()),
+        },
+    ]
+}
+
+#[test]
+fn test_the_report_table_shows_where_the_time_goes() {
+    let mut output = vec![];
+    write_table(&test_measurements(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+
+    assert_eq!(
+        lines[0],
+        "Day  Part        Read       Parse       Solve       Total   Share"
+    );
+    assert_eq!(
+        lines[1],
+        "01   1         10.0µs      20.0µs      70.0µs     100.0µs   20.0%"
+    );
+    assert_eq!(
+        lines[2],
+        "04   2         10.0µs      90.0µs     300.0µs     400.0µs   80.0%"
+    );
+    assert_eq!(lines[3], "05   1     no input for day 5, sadly");
+    assert_eq!(
+        lines[4],
+        "Total                                             500.0µs"
+    );
+    assert_eq!(lines[5], "Slowest: day 04 part 2 (80.0% of the year)");
+}
+
+#[test]
+fn test_the_report_can_be_written_as_csv() {
+    let mut output = vec![];
+    write_csv(&test_measurements(), &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "day,part,read_ns,parse_ns,solve_ns,total_ns,error
+1,1,10000,20000,70000,100000,
+4,2,10000,90000,300000,400000,
+5,1,,,,,\"no input for day 5, sadly\"
+"
+    );
+}
+
+#[test]
+fn test_we_can_measure_every_registered_solver() {
+    use crate::Solver;
+    use crate::solver::test_solver;
+
+    let dir = std::env::temp_dir().join(format!("toolbox-report-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("2025")).unwrap();
+    std::fs::write(dir.join("2025/01.txt"), "a b c\n").unwrap();
+    std::fs::write(dir.join("2025/02.txt"), "\n").unwrap();
+
+    let registry = Registry::from_iter([[
+        test_solver!(1, 1, |input: &str| input.len()),
+        Solver {
+            parse: |_| Err("expected words".to_string()),
+            ..test_solver!(2, 1, |_| "")
+        },
+        Solver {
+            parse: |_| unreachable!("there is no input for day 3"),
+            ..test_solver!(3, 1, |_| "")
+        },
+    ]
+    .as_slice()]);
+
+    let measurements = measure(&registry, &InputStore::new(&dir), None);
+    let phases = measurements[0].phases.as_ref().unwrap();
+    assert_eq!(phases.total(), phases.read + phases.parse + phases.solve);
+    assert_eq!(
+        measurements[1].phases,
+        Err("failed to parse input: expected words".to_string())
+    );
+    assert!(
+        measurements[2]
+            .phases
+            .as_ref()
+            .is_err_and(|message| message.contains("no input for day 3"))
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
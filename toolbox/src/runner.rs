@@ -0,0 +1,1247 @@
+use crate::input_store::{InputError, InputStore};
+use crate::random::Rng;
+#[cfg(test)]
+use crate::solver::test_solver;
+use crate::{
+    Registry, Solver, dashboard, fuzz, parallel, report, scaffold, snapshot, trace, verify,
+};
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+pub const USAGE: &str = "usage: aoc --day <day> [--part <part>] [--input <path|->]
+       aoc --day <day> [--part <part>] [--store] [--input-dir <dir>] [--variant <name>]
+       aoc --all [--input-dir <dir>] [--variant <name>]
+       aoc verify [--answers <path>] [--input-dir <dir>]
+       aoc report [--input-dir <dir>] [--variant <name>] [--csv <path>]
+       aoc dashboard [--answers <path>] [--input-dir <dir>] [--html <path>]
+       aoc new <day> <title>
+       aoc fuzz [--day <day>] [--runs <n>] [--seed <n>] [--corpus-dir <dir>]
+       aoc gen --day <day> [--size <n>] [--seed <n>]
+       aoc snapshot [--day <day>] [--update] [--snapshot-dir <dir>] [--corpus-dir <dir>]
+
+Add -v or -vv to any command to log what the solvers do to stderr, and --threads <n> to set
+how many threads the solvers that split their work may use (one per core by default).
+
+A day's input is read from stdin unless --input names a file, or --store, --input-dir or
+--variant reads it from <dir>/2025/<day>.txt (or <day>.<name>.txt), where <dir> defaults to
+$AOC_INPUT_DIR or the workspace's input directory.";
+
+pub const DEFAULT_GENERATED_SIZE: usize = 100;
+
+#[derive(Debug, PartialEq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+    Store {
+        input_dir: Option<PathBuf>,
+        variant: Option<String>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        day: u8,
+        part: Option<u8>,
+        input: Input,
+    },
+    All {
+        input_dir: Option<PathBuf>,
+        variant: Option<String>,
+    },
+    Verify {
+        answers: PathBuf,
+        input_dir: Option<PathBuf>,
+    },
+    Report {
+        input_dir: Option<PathBuf>,
+        variant: Option<String>,
+        csv: Option<PathBuf>,
+    },
+    Dashboard {
+        answers: PathBuf,
+        input_dir: Option<PathBuf>,
+        html: Option<PathBuf>,
+    },
+    New {
+        day: u8,
+        title: String,
+    },
+    Fuzz {
+        day: Option<u8>,
+        runs: u64,
+        seed: u64,
+        corpus_dir: Option<PathBuf>,
+    },
+    Generate {
+        day: u8,
+        size: usize,
+        seed: u64,
+    },
+    Snapshot {
+        day: Option<u8>,
+        update: bool,
+        snapshot_dir: Option<PathBuf>,
+        corpus_dir: Option<PathBuf>,
+    },
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RunnerError {
+    Usage(String),
+    UnknownSolver { day: u8, part: Option<u8> },
+    NoGenerator { day: u8 },
+    Input { path: PathBuf, message: String },
+    Output { path: PathBuf, message: String },
+    MissingInput(InputError),
+    Parse { day: u8, part: u8, message: String },
+    Answers { path: PathBuf, message: String },
+    Verification { failed: usize },
+    Unsolved { failed: usize },
+    Crashes { crashed: usize },
+    Snapshots { failed: usize },
+    Scaffold(scaffold::ScaffoldError),
+}
+
+impl Display for RunnerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunnerError::Usage(message) => write!(f, "{message}\n{USAGE}"),
+            RunnerError::UnknownSolver {
+                day,
+                part: Some(part),
+            } => write!(f, "no solver registered for day {day} part {part}"),
+            RunnerError::UnknownSolver { day, part: None } => {
+                write!(f, "no solver registered for day {day}")
+            }
+            RunnerError::NoGenerator { day } => {
+                write!(f, "day {day} has no input generator")
+            }
+            RunnerError::Input { path, message } => {
+                write!(f, "failed to read {}: {message}", path.display())
+            }
+            RunnerError::Output { path, message } => {
+                write!(f, "failed to write {}: {message}", path.display())
+            }
+            RunnerError::MissingInput(e) => e.fmt(f),
+            RunnerError::Parse { day, part, message } => {
+                write!(f, "day {day} part {part}: failed to parse input: {message}")
+            }
+            RunnerError::Answers { path, message } => {
+                write!(f, "failed to parse {}: {message}", path.display())
+            }
+            RunnerError::Verification { failed } => {
+                write!(f, "{failed} answer(s) did not verify")
+            }
+            RunnerError::Unsolved { failed } => {
+                write!(f, "{failed} part(s) failed to solve")
+            }
+            RunnerError::Crashes { crashed } => {
+                write!(f, "{crashed} parser(s) panicked")
+            }
+            RunnerError::Snapshots { failed } => {
+                write!(f, "{failed} snapshot(s) did not match")
+            }
+            RunnerError::Scaffold(e) => e.fmt(f),
+        }
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, RunnerError> {
+    let value = value.ok_or_else(|| RunnerError::Usage(format!("{flag} needs a value")))?;
+    value
+        .parse()
+        .map_err(|_| RunnerError::Usage(format!("{flag} expects a number, got {value:?}")))
+}
+
+fn parse_value(flag: &str, value: Option<String>) -> Result<String, RunnerError> {
+    value.ok_or_else(|| RunnerError::Usage(format!("{flag} needs a value")))
+}
+
+fn parse_path(flag: &str, value: Option<String>) -> Result<PathBuf, RunnerError> {
+    parse_value(flag, value).map(PathBuf::from)
+}
+
+impl Command {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, RunnerError> {
+        let mut args = args.into_iter().peekable();
+        match args.peek().map(String::as_str) {
+            Some("verify") => {
+                args.next();
+                Self::parse_verify(args)
+            }
+            Some("report") => {
+                args.next();
+                Self::parse_report(args)
+            }
+            Some("dashboard") => {
+                args.next();
+                Self::parse_dashboard(args)
+            }
+            Some("new") => {
+                args.next();
+                Self::parse_new(args)
+            }
+            Some("fuzz") => {
+                args.next();
+                Self::parse_fuzz(args)
+            }
+            Some("gen") => {
+                args.next();
+                Self::parse_generate(args)
+            }
+            Some("snapshot") => {
+                args.next();
+                Self::parse_snapshot(args)
+            }
+            _ => Self::parse_run(args),
+        }
+    }
+
+    fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<Self, RunnerError> {
+        let mut answers = None;
+        let mut input_dir = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--answers" | "-a" => answers = Some(parse_path(&arg, args.next())?),
+                "--input-dir" => input_dir = Some(parse_path(&arg, args.next())?),
+                "--help" | "-h" => return Ok(Command::Help),
+                _ => return Err(RunnerError::Usage(format!("unexpected argument {arg:?}"))),
+            }
+        }
+        Ok(Command::Verify {
+            answers: answers.unwrap_or_else(|| PathBuf::from(verify::DEFAULT_ANSWERS_FILE)),
+            input_dir,
+        })
+    }
+
+    fn parse_report(mut args: impl Iterator<Item = String>) -> Result<Self, RunnerError> {
+        let mut input_dir = None;
+        let mut variant = None;
+        let mut csv = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input-dir" => input_dir = Some(parse_path(&arg, args.next())?),
+                "--variant" => variant = Some(parse_value(&arg, args.next())?),
+                "--csv" => csv = Some(parse_path(&arg, args.next())?),
+                "--help" | "-h" => return Ok(Command::Help),
+                _ => return Err(RunnerError::Usage(format!("unexpected argument {arg:?}"))),
+            }
+        }
+        Ok(Command::Report {
+            input_dir,
+            variant,
+            csv,
+        })
+    }
+
+    fn parse_dashboard(mut args: impl Iterator<Item = String>) -> Result<Self, RunnerError> {
+        let mut answers = None;
+        let mut input_dir = None;
+        let mut html = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--answers" | "-a" => answers = Some(parse_path(&arg, args.next())?),
+                "--input-dir" => input_dir = Some(parse_path(&arg, args.next())?),
+                "--html" => html = Some(parse_path(&arg, args.next())?),
+                "--help" | "-h" => return Ok(Command::Help),
+                _ => return Err(RunnerError::Usage(format!("unexpected argument {arg:?}"))),
+            }
+        }
+        Ok(Command::Dashboard {
+            answers: answers.unwrap_or_else(|| PathBuf::from(verify::DEFAULT_ANSWERS_FILE)),
+            input_dir,
+            html,
+        })
+    }
+
+    fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Self, RunnerError> {
+        let day = parse_number("new", args.next())?;
+        let title = args.collect::<Vec<_>>().join(" ");
+        if title.trim().is_empty() {
+            return Err(RunnerError::Usage("new needs a puzzle title".to_string()));
+        }
+        Ok(Command::New { day, title })
+    }
+
+    fn parse_fuzz(mut args: impl Iterator<Item = String>) -> Result<Self, RunnerError> {
+        let mut day = None;
+        let mut runs = fuzz::DEFAULT_RUNS;
+        let mut seed = 0;
+        let mut corpus_dir = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+                "--runs" => runs = parse_number(&arg, args.next())?,
+                "--seed" => seed = parse_number(&arg, args.next())?,
+                "--corpus-dir" => corpus_dir = Some(parse_path(&arg, args.next())?),
+                "--help" | "-h" => return Ok(Command::Help),
+                _ => return Err(RunnerError::Usage(format!("unexpected argument {arg:?}"))),
+            }
+        }
+        Ok(Command::Fuzz {
+            day,
+            runs,
+            seed,
+            corpus_dir,
+        })
+    }
+
+    fn parse_generate(mut args: impl Iterator<Item = String>) -> Result<Self, RunnerError> {
+        let mut day = None;
+        let mut size = DEFAULT_GENERATED_SIZE;
+        let mut seed = 0;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+                "--size" => size = parse_number(&arg, args.next())?,
+                "--seed" => seed = parse_number(&arg, args.next())?,
+                "--help" | "-h" => return Ok(Command::Help),
+                _ => return Err(RunnerError::Usage(format!("unexpected argument {arg:?}"))),
+            }
+        }
+        let day = day.ok_or_else(|| RunnerError::Usage("gen needs --day".to_string()))?;
+        Ok(Command::Generate { day, size, seed })
+    }
+
+    fn parse_snapshot(mut args: impl Iterator<Item = String>) -> Result<Self, RunnerError> {
+        let mut day = None;
+        let mut update = false;
+        let mut snapshot_dir = None;
+        let mut corpus_dir = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+                "--update" => update = true,
+                "--snapshot-dir" => snapshot_dir = Some(parse_path(&arg, args.next())?),
+                "--corpus-dir" => corpus_dir = Some(parse_path(&arg, args.next())?),
+                "--help" | "-h" => return Ok(Command::Help),
+                _ => return Err(RunnerError::Usage(format!("unexpected argument {arg:?}"))),
+            }
+        }
+        Ok(Command::Snapshot {
+            day,
+            update,
+            snapshot_dir,
+            corpus_dir,
+        })
+    }
+
+    fn parse_run(args: impl IntoIterator<Item = String>) -> Result<Self, RunnerError> {
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        let mut input_dir = None;
+        let mut variant = None;
+        let mut store = false;
+        let mut all = false;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" | "-d" => day = Some(parse_number(&arg, args.next())?),
+                "--part" | "-p" => part = Some(parse_number(&arg, args.next())?),
+                "--input" | "-i" => input = Some(parse_path(&arg, args.next())?),
+                "--input-dir" => input_dir = Some(parse_path(&arg, args.next())?),
+                "--variant" => variant = Some(parse_value(&arg, args.next())?),
+                "--store" => store = true,
+                "--all" => all = true,
+                "--help" | "-h" => return Ok(Command::Help),
+                _ => return Err(RunnerError::Usage(format!("unexpected argument {arg:?}"))),
+            }
+        }
+
+        let store = store || input_dir.is_some() || variant.is_some();
+        if input.is_some() && store {
+            return Err(RunnerError::Usage(
+                "--input cannot be combined with --store, --input-dir or --variant".to_string(),
+            ));
+        }
+
+        match (all, day) {
+            (true, None) if part.is_none() && input.is_none() => {
+                Ok(Command::All { input_dir, variant })
+            }
+            (true, _) => Err(RunnerError::Usage(
+                "--all cannot be combined with --day, --part or --input".to_string(),
+            )),
+            (false, Some(day)) => Ok(Command::Run {
+                day,
+                part,
+                input: match input {
+                    _ if store => Input::Store { input_dir, variant },
+                    Some(path) if path.as_os_str() != "-" => Input::File(path),
+                    _ => Input::Stdin,
+                },
+            }),
+            (false, None) => Err(RunnerError::Usage(
+                "either --day or --all is required".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_we_can_parse_runner_arguments() {
+    assert_eq!(
+        Command::parse(args(&["--day", "5", "--part", "2"])),
+        Ok(Command::Run {
+            day: 5,
+            part: Some(2),
+            input: Input::Stdin
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["--day", "5", "--store"])),
+        Ok(Command::Run {
+            day: 5,
+            part: None,
+            input: Input::Store {
+                input_dir: None,
+                variant: None
+            }
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["-d", "11", "-i", "input.txt"])),
+        Ok(Command::Run {
+            day: 11,
+            part: None,
+            input: Input::File(PathBuf::from("input.txt"))
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["--day", "1", "--input", "-"])),
+        Ok(Command::Run {
+            day: 1,
+            part: None,
+            input: Input::Stdin
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&[
+            "--day",
+            "5",
+            "--input-dir",
+            "puzzles",
+            "--variant",
+            "sample"
+        ])),
+        Ok(Command::Run {
+            day: 5,
+            part: None,
+            input: Input::Store {
+                input_dir: Some(PathBuf::from("puzzles")),
+                variant: Some("sample".to_string())
+            }
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["--all"])),
+        Ok(Command::All {
+            input_dir: None,
+            variant: None
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["--all", "--input-dir", "puzzles"])),
+        Ok(Command::All {
+            input_dir: Some(PathBuf::from("puzzles")),
+            variant: None
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["verify"])),
+        Ok(Command::Verify {
+            answers: PathBuf::from("answers.toml"),
+            input_dir: None
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&[
+            "verify",
+            "--answers",
+            "real.toml",
+            "--input-dir",
+            "x"
+        ])),
+        Ok(Command::Verify {
+            answers: PathBuf::from("real.toml"),
+            input_dir: Some(PathBuf::from("x"))
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["report"])),
+        Ok(Command::Report {
+            input_dir: None,
+            variant: None,
+            csv: None
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&[
+            "report",
+            "--variant",
+            "sample",
+            "--csv",
+            "times.csv"
+        ])),
+        Ok(Command::Report {
+            input_dir: None,
+            variant: Some("sample".to_string()),
+            csv: Some(PathBuf::from("times.csv"))
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["dashboard"])),
+        Ok(Command::Dashboard {
+            answers: PathBuf::from("answers.toml"),
+            input_dir: None,
+            html: None
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&[
+            "dashboard",
+            "--html",
+            "year.html",
+            "-a",
+            "real.toml"
+        ])),
+        Ok(Command::Dashboard {
+            answers: PathBuf::from("real.toml"),
+            input_dir: None,
+            html: Some(PathBuf::from("year.html"))
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["new", "07", "Laboratories"])),
+        Ok(Command::New {
+            day: 7,
+            title: "Laboratories".to_string()
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["new", "12", "Christmas", "Tree", "Farm"])),
+        Ok(Command::New {
+            day: 12,
+            title: "Christmas Tree Farm".to_string()
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["fuzz"])),
+        Ok(Command::Fuzz {
+            day: None,
+            runs: 10_000,
+            seed: 0,
+            corpus_dir: None
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&[
+            "fuzz",
+            "--day",
+            "3",
+            "--runs",
+            "500",
+            "--seed",
+            "42",
+            "--corpus-dir",
+            "seeds"
+        ])),
+        Ok(Command::Fuzz {
+            day: Some(3),
+            runs: 500,
+            seed: 42,
+            corpus_dir: Some(PathBuf::from("seeds"))
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["gen", "--day", "3"])),
+        Ok(Command::Generate {
+            day: 3,
+            size: 100,
+            seed: 0
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["gen", "-d", "11", "--size", "5000", "--seed", "7"])),
+        Ok(Command::Generate {
+            day: 11,
+            size: 5000,
+            seed: 7
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&["snapshot"])),
+        Ok(Command::Snapshot {
+            day: None,
+            update: false,
+            snapshot_dir: None,
+            corpus_dir: None
+        })
+    );
+    assert_eq!(
+        Command::parse(args(&[
+            "snapshot",
+            "--update",
+            "--day",
+            "5",
+            "--snapshot-dir",
+            "snaps",
+            "--corpus-dir",
+            "seeds"
+        ])),
+        Ok(Command::Snapshot {
+            day: Some(5),
+            update: true,
+            snapshot_dir: Some(PathBuf::from("snaps")),
+            corpus_dir: Some(PathBuf::from("seeds"))
+        })
+    );
+    assert_eq!(Command::parse(args(&["--help"])), Ok(Command::Help));
+}
+
+#[test]
+fn test_we_reject_malformed_runner_arguments() {
+    assert!(Command::parse(args(&[])).is_err());
+    assert!(Command::parse(args(&["--day"])).is_err());
+    assert!(Command::parse(args(&["--day", "five"])).is_err());
+    assert!(Command::parse(args(&["--day", "5", "--bogus"])).is_err());
+    assert!(Command::parse(args(&["--part", "1"])).is_err());
+    assert!(Command::parse(args(&["--all", "--day", "5"])).is_err());
+    assert!(Command::parse(args(&["--day", "5", "--input", "a", "--input-dir", "x"])).is_err());
+    assert!(Command::parse(args(&["--day", "5", "--input", "-", "--store"])).is_err());
+    assert!(Command::parse(args(&["--day", "5", "--variant"])).is_err());
+    assert!(Command::parse(args(&["verify", "--day", "5"])).is_err());
+    assert!(Command::parse(args(&["report", "--csv"])).is_err());
+    assert!(Command::parse(args(&["report", "--day", "5"])).is_err());
+    assert!(Command::parse(args(&["dashboard", "--html"])).is_err());
+    assert!(Command::parse(args(&["dashboard", "--csv", "x"])).is_err());
+    assert!(Command::parse(args(&["new", "07"])).is_err());
+    assert!(Command::parse(args(&["new", "Laboratories"])).is_err());
+    assert!(Command::parse(args(&["fuzz", "--runs", "many"])).is_err());
+    assert!(Command::parse(args(&["fuzz", "--part", "1"])).is_err());
+    assert!(Command::parse(args(&["gen"])).is_err());
+    assert!(Command::parse(args(&["snapshot", "--snapshot-dir"])).is_err());
+    assert!(Command::parse(args(&["snapshot", "--runs", "5"])).is_err());
+    assert!(Command::parse(args(&["gen", "--day", "3", "--size", "-1"])).is_err());
+}
+
+fn input_store(input_dir: Option<PathBuf>) -> InputStore {
+    input_dir
+        .map(InputStore::new)
+        .unwrap_or_else(InputStore::locate)
+}
+
+fn read_input(day: u8, input: Input) -> Result<String, RunnerError> {
+    match input {
+        Input::Stdin => {
+            crate::read_to_string(&mut std::io::stdin().lock()).map_err(|e| RunnerError::Input {
+                path: PathBuf::from("<stdin>"),
+                message: e.to_string(),
+            })
+        }
+        Input::File(path) => std::fs::read_to_string(&path).map_err(|e| RunnerError::Input {
+            path,
+            message: e.to_string(),
+        }),
+        Input::Store { input_dir, variant } => input_store(input_dir)
+            .read(day, variant.as_deref())
+            .map_err(RunnerError::MissingInput),
+    }
+}
+
+fn read_answers(path: &Path) -> Result<Vec<verify::ExpectedAnswer>, RunnerError> {
+    let contents = std::fs::read_to_string(path).map_err(|e| RunnerError::Input {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    verify::parse_answers(&contents).map_err(|e| RunnerError::Answers {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+fn workspace_root() -> Result<PathBuf, RunnerError> {
+    let current_dir = std::env::current_dir().map_err(|e| RunnerError::Input {
+        path: PathBuf::from("."),
+        message: e.to_string(),
+    })?;
+    scaffold::find_workspace_root(&current_dir).map_err(RunnerError::Scaffold)
+}
+
+fn solve(solver: &Solver, input: &str) -> Result<String, RunnerError> {
+    solver
+        .run(input)
+        .map(|answer| answer.answer)
+        .map_err(|message| RunnerError::Parse {
+            day: solver.day,
+            part: solver.part,
+            message,
+        })
+}
+
+fn write_labelled(output: &mut dyn Write, solver: &Solver, answer: &str) {
+    writeln!(
+        output,
+        "Day {:02} part {}: {answer}",
+        solver.day, solver.part
+    )
+    .expect("failed to write output");
+}
+
+pub fn run(
+    registry: &Registry,
+    command: Command,
+    output: &mut dyn Write,
+) -> Result<(), RunnerError> {
+    match command {
+        Command::Help => {
+            writeln!(output, "{USAGE}").expect("failed to write output");
+        }
+        Command::Run {
+            day,
+            part: Some(part),
+            input,
+        } => {
+            let solver = registry.find(day, part).ok_or(RunnerError::UnknownSolver {
+                day,
+                part: Some(part),
+            })?;
+            let input = read_input(day, input)?;
+            writeln!(output, "{}", solve(solver, &input)?).expect("failed to write output");
+        }
+        Command::Run {
+            day,
+            part: None,
+            input,
+        } => {
+            if registry.day(day).next().is_none() {
+                return Err(RunnerError::UnknownSolver { day, part: None });
+            }
+            let input = read_input(day, input)?;
+            for solver in registry.day(day) {
+                write_labelled(output, solver, &solve(solver, &input)?);
+            }
+        }
+        Command::All { input_dir, variant } => {
+            let store = input_store(input_dir);
+            let mut failed = 0;
+            for solver in registry.iter() {
+                match store.read(solver.day, variant.as_deref()) {
+                    Ok(input) => match solver.run(&input) {
+                        Ok(answer) => write_labelled(output, solver, &answer.answer),
+                        Err(message) => {
+                            write_labelled(output, solver, &format!("error: {message}"));
+                            failed += 1;
+                        }
+                    },
+                    Err(e) => eprintln!("skipping day {} part {}: {e}", solver.day, solver.part),
+                }
+            }
+            if failed > 0 {
+                return Err(RunnerError::Unsolved { failed });
+            }
+        }
+        Command::Verify { answers, input_dir } => {
+            let expected = read_answers(&answers)?;
+            let base_dir = answers.parent().unwrap_or(Path::new(""));
+            let verifications =
+                verify::verify(registry, &expected, base_dir, &input_store(input_dir));
+            verify::write_report(&verifications, output).expect("failed to write output");
+
+            let failed = verifications.iter().filter(|v| v.failed()).count();
+            if failed > 0 {
+                return Err(RunnerError::Verification { failed });
+            }
+        }
+        Command::Report {
+            input_dir,
+            variant,
+            csv,
+        } => {
+            let measurements =
+                report::measure(registry, &input_store(input_dir), variant.as_deref());
+            report::write_table(&measurements, output).expect("failed to write output");
+            if let Some(path) = csv {
+                std::fs::File::create(&path)
+                    .and_then(|mut file| report::write_csv(&measurements, &mut file))
+                    .map_err(|e| RunnerError::Output {
+                        path,
+                        message: e.to_string(),
+                    })?;
+            }
+        }
+        Command::Dashboard {
+            answers,
+            input_dir,
+            html,
+        } => {
+            let manifest_path = workspace_root()?.join("Cargo.toml");
+            let manifest =
+                std::fs::read_to_string(&manifest_path).map_err(|e| RunnerError::Input {
+                    path: manifest_path.clone(),
+                    message: e.to_string(),
+                })?;
+            let members =
+                dashboard::workspace_days(&manifest).map_err(|message| RunnerError::Input {
+                    path: manifest_path,
+                    message,
+                })?;
+            // Without an answers file every registered part still shows as solved.
+            let expected = if answers.exists() {
+                read_answers(&answers)?
+            } else {
+                vec![]
+            };
+            let base_dir = answers.parent().unwrap_or(Path::new(""));
+            let verifications =
+                verify::verify(registry, &expected, base_dir, &input_store(input_dir));
+            let days = dashboard::progress(&members, registry, &verifications);
+            dashboard::write_table(&days, output).expect("failed to write output");
+            if let Some(path) = html {
+                std::fs::File::create(&path)
+                    .and_then(|mut file| dashboard::write_html(&days, &mut file))
+                    .map_err(|e| RunnerError::Output {
+                        path,
+                        message: e.to_string(),
+                    })?;
+            }
+        }
+        Command::New { day, title } => {
+            let day_dir = scaffold::new_day(&workspace_root()?, day, &title)
+                .map_err(RunnerError::Scaffold)?;
+            writeln!(output, "created {}", day_dir.display()).expect("failed to write output");
+        }
+        Command::Fuzz {
+            day,
+            runs,
+            seed,
+            corpus_dir,
+        } => {
+            if let Some(day) = day.filter(|&day| registry.day(day).next().is_none()) {
+                return Err(RunnerError::UnknownSolver { day, part: None });
+            }
+            let corpus = corpus_dir
+                .map(fuzz::Corpus::new)
+                .unwrap_or_else(fuzz::Corpus::locate);
+            let crashes = fuzz::fuzz(registry, &corpus, day, runs, seed, output)
+                .expect("failed to write output");
+            if !crashes.is_empty() {
+                return Err(RunnerError::Crashes {
+                    crashed: crashes.len(),
+                });
+            }
+        }
+        Command::Generate { day, size, seed } => {
+            // Both parts of a day share its generator.
+            let solver = registry
+                .day(day)
+                .next()
+                .ok_or(RunnerError::UnknownSolver { day, part: None })?;
+            let input = (solver.generate)(&mut Rng::new(seed), size)
+                .ok_or(RunnerError::NoGenerator { day })?;
+            write!(output, "{input}").expect("failed to write output");
+        }
+        Command::Snapshot {
+            day,
+            update,
+            snapshot_dir,
+            corpus_dir,
+        } => {
+            if let Some(day) = day.filter(|&day| registry.day(day).next().is_none()) {
+                return Err(RunnerError::UnknownSolver { day, part: None });
+            }
+            let snapshots = snapshot_dir
+                .map(snapshot::Snapshots::new)
+                .unwrap_or_else(snapshot::Snapshots::locate);
+            let corpus = corpus_dir
+                .map(fuzz::Corpus::new)
+                .unwrap_or_else(fuzz::Corpus::locate);
+            let failed = snapshot::check(registry, &snapshots, &corpus, day, update, output)
+                .map_err(|e| RunnerError::Output {
+                    path: snapshots.dir().to_path_buf(),
+                    message: e.to_string(),
+                })?;
+            if failed > 0 {
+                return Err(RunnerError::Snapshots { failed });
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+fn test_registry() -> Registry {
+    Registry::from_iter([[
+        test_solver!(1, 1, |input: &str| input.lines().count()),
+        test_solver!(1, 2, |input: &str| input.len()),
+    ]
+    .as_slice()])
+}
+
+#[test]
+fn test_the_runner_prints_a_bare_answer_for_a_single_part() {
+    let dir = std::env::temp_dir().join(format!("toolbox-runner-single-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("input.txt"), "a\nb\nc\n").unwrap();
+
+    let mut output = vec![];
+    run(
+        &test_registry(),
+        Command::Run {
+            day: 1,
+            part: Some(1),
+            input: Input::File(dir.join("input.txt")),
+        },
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "3\n");
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_the_runner_labels_every_answer_when_running_several_parts() {
+    let dir = std::env::temp_dir().join(format!("toolbox-runner-all-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::create_dir_all(dir.join("2025")).unwrap();
+    std::fs::write(dir.join("2025/01.txt"), "a\nb\n").unwrap();
+
+    let mut output = vec![];
+    run(
+        &test_registry(),
+        Command::All {
+            input_dir: Some(dir.clone()),
+            variant: None,
+        },
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "Day 01 part 1: 2\nDay 01 part 2: 4\n"
+    );
+
+    let mut output = vec![];
+    run(
+        &test_registry(),
+        Command::Run {
+            day: 1,
+            part: None,
+            input: Input::File(dir.join("2025/01.txt")),
+        },
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "Day 01 part 1: 2\nDay 01 part 2: 4\n"
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_running_every_day_carries_on_past_a_failing_day() {
+    let dir = std::env::temp_dir().join(format!("toolbox-runner-failing-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("2025")).unwrap();
+    for day in ["01", "02", "03"] {
+        std::fs::write(dir.join(format!("2025/{day}.txt")), "a\nb\n").unwrap();
+    }
+    let registry = Registry::from_iter([[
+        test_solver!(1, 1, |input: &str| input.lines().count()),
+        Solver {
+            solve: |_| Err("expected a number, found \"a\"".to_string()),
+            ..test_solver!(2, 1, |_| 0)
+        },
+        test_solver!(3, 1, |input: &str| input.len()),
+    ]
+    .as_slice()]);
+
+    let mut output = vec![];
+    assert_eq!(
+        run(
+            &registry,
+            Command::All {
+                input_dir: Some(dir.clone()),
+                variant: None,
+            },
+            &mut output,
+        ),
+        Err(RunnerError::Unsolved { failed: 1 })
+    );
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "Day 01 part 1: 2\nDay 02 part 1: error: expected a number, found \"a\"\nDay 03 part 1: 4\n"
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_the_runner_reports_unknown_solvers_and_missing_inputs() {
+    assert_eq!(
+        run(
+            &test_registry(),
+            Command::Run {
+                day: 2,
+                part: Some(1),
+                input: Input::Stdin
+            },
+            &mut vec![]
+        ),
+        Err(RunnerError::UnknownSolver {
+            day: 2,
+            part: Some(1)
+        })
+    );
+    assert_eq!(
+        run(
+            &test_registry(),
+            Command::Run {
+                day: 2,
+                part: None,
+                input: Input::Stdin
+            },
+            &mut vec![]
+        ),
+        Err(RunnerError::UnknownSolver { day: 2, part: None })
+    );
+    assert!(matches!(
+        run(
+            &test_registry(),
+            Command::Run {
+                day: 1,
+                part: Some(1),
+                input: Input::File(PathBuf::from("/does/not/exist"))
+            },
+            &mut vec![]
+        ),
+        Err(RunnerError::Input { .. })
+    ));
+    assert_eq!(
+        run(
+            &test_registry(),
+            Command::Run {
+                day: 1,
+                part: Some(1),
+                input: Input::Store {
+                    input_dir: Some(PathBuf::from("/does/not/exist")),
+                    variant: Some("sample".to_string())
+                }
+            },
+            &mut vec![]
+        ),
+        Err(RunnerError::MissingInput(InputError::Missing {
+            day: 1,
+            variant: Some("sample".to_string()),
+            path: PathBuf::from("/does/not/exist/2025/01.sample.txt")
+        }))
+    );
+}
+
+#[test]
+fn test_the_runner_fuzzes_each_registered_parser() {
+    let mut output = vec![];
+    run(
+        &test_registry(),
+        Command::Fuzz {
+            day: None,
+            runs: 100,
+            seed: 0,
+            corpus_dir: Some(PathBuf::from("/does/not/exist")),
+        },
+        &mut output,
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "Day 01 part 1: 100 inputs, no panics\nDay 01 part 2: 100 inputs, no panics\n"
+    );
+    assert_eq!(
+        run(
+            &test_registry(),
+            Command::Fuzz {
+                day: Some(2),
+                runs: 100,
+                seed: 0,
+                corpus_dir: None,
+            },
+            &mut vec![]
+        ),
+        Err(RunnerError::UnknownSolver { day: 2, part: None })
+    );
+}
+
+#[test]
+fn test_the_runner_writes_generated_input() {
+    let registry = Registry::from_iter([[Solver {
+        generate: |rng, size| Some(crate::random::bank(rng, size) + "\n"),
+        ..test_solver!(3, 1, |input: &str| input.len())
+    }]
+    .as_slice()]);
+    let generate = |seed| {
+        let mut output = vec![];
+        run(
+            &registry,
+            Command::Generate {
+                day: 3,
+                size: 12,
+                seed,
+            },
+            &mut output,
+        )
+        .unwrap();
+        String::from_utf8(output).unwrap()
+    };
+    assert_eq!(generate(1).len(), 13);
+    assert_eq!(generate(1), generate(1));
+    assert_ne!(generate(1), generate(2));
+
+    let command = |day| Command::Generate {
+        day,
+        size: 12,
+        seed: 0,
+    };
+    assert_eq!(
+        run(&test_registry(), command(1), &mut vec![]),
+        Err(RunnerError::NoGenerator { day: 1 })
+    );
+    assert_eq!(
+        run(&registry, command(2), &mut vec![]),
+        Err(RunnerError::UnknownSolver { day: 2, part: None })
+    );
+}
+
+#[test]
+fn test_the_runner_fails_when_a_snapshot_does_not_match() {
+    let dir = std::env::temp_dir().join(format!("toolbox-runner-snapshot-{}", std::process::id()));
+    let command = |update| Command::Snapshot {
+        day: None,
+        update,
+        snapshot_dir: Some(dir.clone()),
+        corpus_dir: Some(PathBuf::from("/does/not/exist")),
+    };
+    run(&test_registry(), command(true), &mut vec![]).unwrap();
+    let mut output = vec![];
+    run(&test_registry(), command(false), &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "Day 01: 0 inputs match\n"
+    );
+    std::fs::write(dir.join("01.snap"), "== sample.txt\npart 1: 3\n").unwrap();
+    assert_eq!(
+        run(&test_registry(), command(false), &mut vec![]),
+        Err(RunnerError::Snapshots { failed: 1 })
+    );
+    assert_eq!(
+        run(
+            &test_registry(),
+            Command::Snapshot {
+                day: Some(2),
+                update: false,
+                snapshot_dir: Some(dir.clone()),
+                corpus_dir: None,
+            },
+            &mut vec![]
+        ),
+        Err(RunnerError::UnknownSolver { day: 2, part: None })
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+// Pulls `-v` and `-vv` out of the arguments wherever they are, so that every command takes them.
+fn take_verbosity(args: impl IntoIterator<Item = String>) -> (u8, Vec<String>) {
+    let mut verbosity = 0;
+    let args = args
+        .into_iter()
+        .filter(|arg| match trace::verbosity_flag(arg) {
+            Some(level) => {
+                verbosity = level;
+                false
+            }
+            None => true,
+        })
+        .collect();
+    (verbosity, args)
+}
+
+#[test]
+fn test_verbosity_flags_are_taken_from_any_command() {
+    assert_eq!(
+        take_verbosity(args(&["--day", "1", "-vv"])),
+        (2, args(&["--day", "1"]))
+    );
+    assert_eq!(
+        take_verbosity(args(&["-v", "verify"])),
+        (1, args(&["verify"]))
+    );
+    assert_eq!(take_verbosity(args(&["--all"])).0, 0);
+}
+
+// As with `-v`, `--threads <n>` can go with any command.
+fn take_threads(args: Vec<String>) -> Result<(Option<usize>, Vec<String>), RunnerError> {
+    let Some(index) = args.iter().position(|arg| arg == "--threads") else {
+        return Ok((None, args));
+    };
+    let threads = args
+        .get(index + 1)
+        .ok_or_else(|| RunnerError::Usage("--threads needs a value".to_string()))?;
+    let threads = parallel::parse_threads(threads).map_err(RunnerError::Usage)?;
+    let mut args = args;
+    args.drain(index..=index + 1);
+    Ok((Some(threads), args))
+}
+
+#[test]
+fn test_a_thread_count_is_taken_from_any_command() {
+    assert_eq!(
+        take_threads(args(&["--all", "--threads", "4"])),
+        Ok((Some(4), args(&["--all"])))
+    );
+    assert_eq!(
+        take_threads(args(&["--threads", "1", "report", "--csv", "x"])),
+        Ok((Some(1), args(&["report", "--csv", "x"])))
+    );
+    assert_eq!(
+        take_threads(args(&["verify"])),
+        Ok((None, args(&["verify"])))
+    );
+    assert!(take_threads(args(&["--all", "--threads"])).is_err());
+    assert!(take_threads(args(&["--all", "--threads", "0"])).is_err());
+}
+
+pub fn main(registry: &Registry) -> ExitCode {
+    let (verbosity, args) = take_verbosity(std::env::args().skip(1));
+    trace::set_verbosity(verbosity);
+    let result = take_threads(args).and_then(|(threads, args)| {
+        if let Some(threads) = threads {
+            parallel::set_threads(threads);
+        }
+        let command = Command::parse(args)?;
+        run(registry, command, &mut std::io::stdout().lock())
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e @ RunnerError::Usage(_)) => {
+            eprintln!("aoc: {e}");
+            ExitCode::from(2)
+        }
+        Err(e) => {
+            eprintln!("aoc: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,477 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE_DIR: &str = "00 - Template";
+const TEMPLATE_CRATE: &str = "template";
+const TEMPLATE_TYPE: &str = "Template";
+const TEMPLATE_DAY: &str = "const DAY: u8 = 0;";
+const RUNNER_DIR: &str = "aoc";
+
+#[derive(Debug, PartialEq)]
+pub enum ScaffoldError {
+    NotAWorkspace(PathBuf),
+    AlreadyExists(String),
+    InvalidTitle(String),
+    Malformed { path: PathBuf, message: String },
+    Io { path: PathBuf, message: String },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::NotAWorkspace(path) => {
+                write!(f, "no workspace Cargo.toml found above {}", path.display())
+            }
+            ScaffoldError::AlreadyExists(what) => write!(f, "{what} already exists"),
+            ScaffoldError::InvalidTitle(title) => {
+                write!(f, "{title:?} does not make a usable crate name")
+            }
+            ScaffoldError::Malformed { path, message } => {
+                write!(f, "could not update {}: {message}", path.display())
+            }
+            ScaffoldError::Io { path, message } => write!(f, "{}: {message}", path.display()),
+        }
+    }
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> ScaffoldError + '_ {
+    move |e| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    }
+}
+
+pub fn crate_name(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+pub fn type_name(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().expect("words are not empty");
+            first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+        })
+        .collect()
+}
+
+#[test]
+fn test_we_can_derive_names_from_a_puzzle_title() {
+    assert_eq!(crate_name("Laboratories"), "laboratories");
+    assert_eq!(crate_name("Secret Entrance"), "secret_entrance");
+    assert_eq!(crate_name("  Trash-Compactor! "), "trash_compactor");
+    assert_eq!(crate_name(""), "");
+    assert_eq!(type_name("Laboratories"), "Laboratories");
+    assert_eq!(type_name("secret entrance"), "SecretEntrance");
+    assert_eq!(type_name("PRINTING department"), "PrintingDepartment");
+}
+
+pub fn day_dir_name(day: u8, title: &str) -> String {
+    format!("{day:02} - {}", title.trim())
+}
+
+fn instantiate(text: &str, day: u8, crate_name: &str, type_name: &str) -> String {
+    text.replace(TEMPLATE_DAY, &format!("const DAY: u8 = {day};"))
+        .replace(TEMPLATE_TYPE, type_name)
+        .replace(TEMPLATE_CRATE, crate_name)
+}
+
+#[test]
+fn test_template_text_is_instantiated_for_a_day() {
+    assert_eq!(
+        instantiate(
+            "use template::Template;\nconst DAY: u8 = 0;\nbinary_path!(\"template_2\")",
+            7,
+            "laboratories",
+            "Laboratories"
+        ),
+        "use laboratories::Laboratories;\nconst DAY: u8 = 7;\nbinary_path!(\"laboratories_2\")"
+    );
+}
+
+pub(crate) fn day_of(entry: &str) -> Option<u8> {
+    let (day, _) = entry.split_once(" - ")?;
+    day.parse().ok()
+}
+
+pub fn add_workspace_member(manifest: &str, member: &str) -> Result<String, String> {
+    let mut lines = manifest.lines().map(str::to_string).collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members = ["))
+        .ok_or("no members list")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or("unterminated members list")?;
+
+    let entries = &lines[start + 1..end];
+    let quoted = format!("\"{member}\"");
+    if entries
+        .iter()
+        .any(|line| line.trim_end_matches(',').trim() == quoted)
+    {
+        return Err(format!("{member} is already a member"));
+    }
+    let offset = entries
+        .iter()
+        .position(|line| line.trim_end_matches(',').trim().trim_matches('"') > member)
+        .unwrap_or(entries.len());
+    lines.insert(start + 1 + offset, format!("    {quoted},"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[test]
+fn test_we_can_add_a_member_to_the_workspace_in_order() {
+    let manifest = "[workspace]\nresolver = \"3\"\nmembers = [\n    \"00 - Template\",\n    \"06 - Trash Compactor\",\n    \"11 - Reactor\",\n    \"aoc\",\n    \"toolbox\",\n]\n";
+    assert_eq!(
+        add_workspace_member(manifest, "07 - Laboratories").unwrap(),
+        "[workspace]\nresolver = \"3\"\nmembers = [\n    \"00 - Template\",\n    \"06 - Trash Compactor\",\n    \"07 - Laboratories\",\n    \"11 - Reactor\",\n    \"aoc\",\n    \"toolbox\",\n]\n"
+    );
+    assert_eq!(
+        add_workspace_member(manifest, "12 - Finale").unwrap(),
+        "[workspace]\nresolver = \"3\"\nmembers = [\n    \"00 - Template\",\n    \"06 - Trash Compactor\",\n    \"11 - Reactor\",\n    \"12 - Finale\",\n    \"aoc\",\n    \"toolbox\",\n]\n"
+    );
+    assert!(add_workspace_member(manifest, "11 - Reactor").is_err());
+    assert!(add_workspace_member("[package]\n", "07 - Laboratories").is_err());
+}
+
+fn registered_days(runner_manifest: &str) -> Vec<(u8, String)> {
+    runner_manifest
+        .lines()
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(" = ")?;
+            let (_, dir) = rest.split_once("path = \"../")?;
+            Some((day_of(dir)?, name.trim().to_string()))
+        })
+        .collect()
+}
+
+pub fn add_runner_dependency(
+    runner_manifest: &str,
+    day: u8,
+    crate_name: &str,
+    dir_name: &str,
+) -> Result<String, String> {
+    let registered = registered_days(runner_manifest);
+    if registered.iter().any(|(d, _)| *d == day) {
+        return Err(format!("day {day} is already a dependency"));
+    }
+
+    let mut lines = runner_manifest
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    let dependency = format!("{crate_name} = {{ path = \"../{dir_name}\" }}");
+    let anchor = registered
+        .iter()
+        .rev()
+        .find(|(d, _)| *d < day)
+        .or_else(|| registered.first());
+    let index = match anchor {
+        Some((d, name)) => {
+            let position = lines
+                .iter()
+                .position(|line| line.starts_with(&format!("{name} = ")))
+                .expect("registered days come from these lines");
+            if *d < day { position + 1 } else { position }
+        }
+        None => {
+            let section = lines
+                .iter()
+                .position(|line| line.trim() == "[dependencies]")
+                .ok_or("no [dependencies] section")?;
+            section
+                + 1
+                + lines[section + 1..]
+                    .iter()
+                    .position(|line| line.trim().is_empty() || line.starts_with('['))
+                    .unwrap_or(lines.len() - section - 1)
+        }
+    };
+    lines.insert(index, dependency);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+pub fn add_runner_registration(
+    runner_lib: &str,
+    runner_manifest: &str,
+    day: u8,
+    crate_name: &str,
+) -> Result<String, String> {
+    let registered = registered_days(runner_manifest);
+    let mut lines = runner_lib.lines().map(str::to_string).collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let name = line.trim().strip_suffix("::SOLVERS,")?;
+            let day = registered
+                .iter()
+                .find(|(_, registered_name)| registered_name == name)
+                .map(|(d, _)| *d)?;
+            Some((
+                i,
+                day,
+                line[..line.len() - line.trim_start().len()].to_string(),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let (index, indent) = match entries.iter().find(|(_, d, _)| *d > day) {
+        Some((i, _, indent)) => (*i, indent.clone()),
+        None => match entries.last() {
+            Some((i, _, indent)) => (i + 1, indent.clone()),
+            None => {
+                let i = lines
+                    .iter()
+                    .position(|line| line.trim_end().ends_with("from_iter(["))
+                    .ok_or("no registry to add to")?;
+                (i + 1, "        ".to_string())
+            }
+        },
+    };
+    lines.insert(index, format!("{indent}{crate_name}::SOLVERS,"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+const RUNNER_MANIFEST: &str = "[package]\nname = \"aoc\"\n\n[dependencies]\ntoolbox = { path = \"../toolbox\" }\nlobby = { path = \"../03 - Lobby\" }\nreactor = { path = \"../11 - Reactor\" }\n";
+
+#[cfg(test)]
+const RUNNER_LIB: &str = "pub fn registry() -> toolbox::Registry {\n    toolbox::Registry::from_iter([\n        lobby::SOLVERS,\n        reactor::SOLVERS,\n    ])\n}\n";
+
+#[test]
+fn test_we_can_add_a_day_to_the_runner_manifest_in_day_order() {
+    assert_eq!(
+        add_runner_dependency(RUNNER_MANIFEST, 7, "laboratories", "07 - Laboratories").unwrap(),
+        "[package]\nname = \"aoc\"\n\n[dependencies]\ntoolbox = { path = \"../toolbox\" }\nlobby = { path = \"../03 - Lobby\" }\nlaboratories = { path = \"../07 - Laboratories\" }\nreactor = { path = \"../11 - Reactor\" }\n"
+    );
+    assert_eq!(
+        add_runner_dependency(
+            RUNNER_MANIFEST,
+            1,
+            "secret_entrance",
+            "01 - Secret Entrance"
+        )
+        .unwrap(),
+        "[package]\nname = \"aoc\"\n\n[dependencies]\ntoolbox = { path = \"../toolbox\" }\nsecret_entrance = { path = \"../01 - Secret Entrance\" }\nlobby = { path = \"../03 - Lobby\" }\nreactor = { path = \"../11 - Reactor\" }\n"
+    );
+    assert_eq!(
+        add_runner_dependency(
+            "[dependencies]\ntoolbox = { path = \"../toolbox\" }\n",
+            1,
+            "secret_entrance",
+            "01 - Secret Entrance"
+        )
+        .unwrap(),
+        "[dependencies]\ntoolbox = { path = \"../toolbox\" }\nsecret_entrance = { path = \"../01 - Secret Entrance\" }\n"
+    );
+    assert!(add_runner_dependency(RUNNER_MANIFEST, 3, "lobby", "03 - Lobby").is_err());
+}
+
+#[test]
+fn test_we_can_register_a_day_with_the_runner_in_day_order() {
+    assert_eq!(
+        add_runner_registration(RUNNER_LIB, RUNNER_MANIFEST, 7, "laboratories").unwrap(),
+        "pub fn registry() -> toolbox::Registry {\n    toolbox::Registry::from_iter([\n        lobby::SOLVERS,\n        laboratories::SOLVERS,\n        reactor::SOLVERS,\n    ])\n}\n"
+    );
+    assert_eq!(
+        add_runner_registration(RUNNER_LIB, RUNNER_MANIFEST, 12, "finale").unwrap(),
+        "pub fn registry() -> toolbox::Registry {\n    toolbox::Registry::from_iter([\n        lobby::SOLVERS,\n        reactor::SOLVERS,\n        finale::SOLVERS,\n    ])\n}\n"
+    );
+    assert!(add_runner_registration("fn main() {}\n", RUNNER_MANIFEST, 7, "laboratories").is_err());
+}
+
+pub fn find_workspace_root(start: &Path) -> Result<PathBuf, ScaffoldError> {
+    start
+        .ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| ScaffoldError::NotAWorkspace(start.to_path_buf()))
+}
+
+fn copy_template(
+    from: &Path,
+    to: &Path,
+    day: u8,
+    crate_name: &str,
+    type_name: &str,
+) -> Result<(), ScaffoldError> {
+    std::fs::create_dir_all(to).map_err(io_error(to))?;
+    for entry in std::fs::read_dir(from).map_err(io_error(from))? {
+        let entry = entry.map_err(io_error(from))?;
+        let source = entry.path();
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        if file_name == "target" {
+            continue;
+        }
+        let destination = to.join(instantiate(&file_name, day, crate_name, type_name));
+        if source.is_dir() {
+            copy_template(&source, &destination, day, crate_name, type_name)?;
+        } else {
+            let text = std::fs::read_to_string(&source).map_err(io_error(&source))?;
+            std::fs::write(&destination, instantiate(&text, day, crate_name, type_name))
+                .map_err(io_error(&destination))?;
+        }
+    }
+    Ok(())
+}
+
+fn update_file(
+    path: &Path,
+    update: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), ScaffoldError> {
+    let text = std::fs::read_to_string(path).map_err(io_error(path))?;
+    let updated = update(&text).map_err(|message| ScaffoldError::Malformed {
+        path: path.to_path_buf(),
+        message,
+    })?;
+    std::fs::write(path, updated).map_err(io_error(path))
+}
+
+pub fn new_day(workspace: &Path, day: u8, title: &str) -> Result<PathBuf, ScaffoldError> {
+    let crate_name = crate_name(title);
+    let type_name = type_name(title);
+    if crate_name.is_empty() || crate_name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(ScaffoldError::InvalidTitle(title.to_string()));
+    }
+
+    let dir_name = day_dir_name(day, title);
+    let day_dir = workspace.join(&dir_name);
+    if day_dir.exists() {
+        return Err(ScaffoldError::AlreadyExists(dir_name));
+    }
+    let runner_manifest_path = workspace.join(RUNNER_DIR).join("Cargo.toml");
+    let runner_manifest =
+        std::fs::read_to_string(&runner_manifest_path).map_err(io_error(&runner_manifest_path))?;
+    if let Some((_, name)) = registered_days(&runner_manifest)
+        .into_iter()
+        .find(|(d, _)| *d == day)
+    {
+        return Err(ScaffoldError::AlreadyExists(format!("day {day} ({name})")));
+    }
+
+    copy_template(
+        &workspace.join(TEMPLATE_DIR),
+        &day_dir,
+        day,
+        &crate_name,
+        &type_name,
+    )?;
+    update_file(&workspace.join("Cargo.toml"), |manifest| {
+        add_workspace_member(manifest, &dir_name)
+    })?;
+    update_file(
+        &workspace.join(RUNNER_DIR).join("src").join("lib.rs"),
+        |lib| add_runner_registration(lib, &runner_manifest, day, &crate_name),
+    )?;
+    update_file(&runner_manifest_path, |manifest| {
+        add_runner_dependency(manifest, day, &crate_name, &dir_name)
+    })?;
+
+    Ok(day_dir)
+}
+
+#[test]
+fn test_we_can_scaffold_a_new_day_from_the_template() {
+    let workspace = std::env::temp_dir().join(format!("toolbox-scaffold-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&workspace);
+    let template = workspace.join(TEMPLATE_DIR);
+    std::fs::create_dir_all(template.join("src/bin")).unwrap();
+    std::fs::create_dir_all(template.join("tests")).unwrap();
+    std::fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+    std::fs::write(
+        template.join("Cargo.toml"),
+        "[package]\nname = \"template\"\n\n[[bin]]\nname = \"template_2\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        template.join("src/lib.rs"),
+        "pub struct Template;\nconst DAY: u8 = 0;\n",
+    )
+    .unwrap();
+    std::fs::write(
+        template.join("src/bin/template_2.rs"),
+        "use template::Template;\n",
+    )
+    .unwrap();
+    std::fs::write(
+        template.join("tests/test_template_2_main.rs"),
+        "toolbox::executable_tests!(\"template_2\", &[]);\n",
+    )
+    .unwrap();
+    std::fs::write(
+        workspace.join("Cargo.toml"),
+        "[workspace]\nmembers = [\n    \"00 - Template\",\n    \"11 - Reactor\",\n    \"aoc\",\n]\n",
+    )
+    .unwrap();
+    std::fs::write(workspace.join("aoc/Cargo.toml"), RUNNER_MANIFEST).unwrap();
+    std::fs::write(workspace.join("aoc/src/lib.rs"), RUNNER_LIB).unwrap();
+
+    let day_dir = new_day(&workspace, 7, "Laboratories").unwrap();
+
+    assert_eq!(day_dir, workspace.join("07 - Laboratories"));
+    assert_eq!(
+        std::fs::read_to_string(day_dir.join("Cargo.toml")).unwrap(),
+        "[package]\nname = \"laboratories\"\n\n[[bin]]\nname = \"laboratories_2\"\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(day_dir.join("src/lib.rs")).unwrap(),
+        "pub struct Laboratories;\nconst DAY: u8 = 7;\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(day_dir.join("src/bin/laboratories_2.rs")).unwrap(),
+        "use laboratories::Laboratories;\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(day_dir.join("tests/test_laboratories_2_main.rs")).unwrap(),
+        "toolbox::executable_tests!(\"laboratories_2\", &[]);\n"
+    );
+    assert!(
+        std::fs::read_to_string(workspace.join("Cargo.toml"))
+            .unwrap()
+            .contains("    \"07 - Laboratories\",\n    \"11 - Reactor\",\n")
+    );
+    assert!(
+        std::fs::read_to_string(workspace.join("aoc/Cargo.toml"))
+            .unwrap()
+            .contains("laboratories = { path = \"../07 - Laboratories\" }\nreactor")
+    );
+    assert!(
+        std::fs::read_to_string(workspace.join("aoc/src/lib.rs"))
+            .unwrap()
+            .contains("        laboratories::SOLVERS,\n        reactor::SOLVERS,\n")
+    );
+
+    assert_eq!(
+        new_day(&workspace, 7, "Laboratories"),
+        Err(ScaffoldError::AlreadyExists(
+            "07 - Laboratories".to_string()
+        ))
+    );
+    assert!(matches!(
+        new_day(&workspace, 11, "Another Reactor"),
+        Err(ScaffoldError::AlreadyExists(_))
+    ));
+    assert_eq!(
+        new_day(&workspace, 8, "!!!"),
+        Err(ScaffoldError::InvalidTitle("!!!".to_string()))
+    );
+
+    std::fs::remove_dir_all(workspace).unwrap();
+}
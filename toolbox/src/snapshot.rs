@@ -0,0 +1,336 @@
+use crate::fuzz::Corpus;
+use crate::random::Rng;
+use crate::{Registry, scaffold};
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_SNAPSHOT_DIR: &str = "snapshots";
+
+// Besides the corpus, each day is recorded against a few small generated inputs. They are seeded,
+// so they come out the same on every machine.
+const GENERATED_SEEDS: u64 = 3;
+const GENERATED_SIZE: usize = 12;
+
+// One snapshot file per day, `<dir>/NN.snap`.
+pub struct Snapshots {
+    dir: PathBuf,
+}
+
+impl Snapshots {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    // The `snapshots` directory at the root of the workspace.
+    pub fn locate() -> Self {
+        let current_dir = std::env::current_dir().unwrap_or_default();
+        let root = scaffold::find_workspace_root(&current_dir).unwrap_or(current_dir);
+        Self::new(root.join(DEFAULT_SNAPSHOT_DIR))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("{day:02}.snap"))
+    }
+}
+
+// What one input produced: each part's answer, or the parse error, then the day's summary.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Section {
+    pub name: String,
+    pub entries: Vec<(String, String)>,
+}
+
+pub fn record(registry: &Registry, day: u8, corpus: &Corpus) -> Vec<Section> {
+    let solvers = registry.day(day).collect::<Vec<_>>();
+    let Some(first) = solvers.first() else {
+        return vec![];
+    };
+    let generated = (0..GENERATED_SEEDS).filter_map(|seed| {
+        let input = (first.generate)(&mut Rng::new(seed), GENERATED_SIZE)?;
+        Some((
+            format!("generated, seed {seed}, size {GENERATED_SIZE}"),
+            input,
+        ))
+    });
+    corpus
+        .seed_files(day)
+        .into_iter()
+        .chain(generated)
+        .map(|(name, input)| {
+            let entries = match (first.parse)(&input) {
+                Ok(parsed) => solvers
+                    .iter()
+                    .map(|solver| {
+                        let answer = (solver.solve)(parsed.as_ref())
+                            .unwrap_or_else(|message| format!("error: {message}"));
+                        (format!("part {}", solver.part), answer)
+                    })
+                    .chain(
+                        (first.summarize)(parsed.as_ref())
+                            .into_iter()
+                            .map(|(key, value)| (key.to_string(), value)),
+                    )
+                    .collect(),
+                Err(message) => vec![("error".to_string(), message)],
+            };
+            Section { name, entries }
+        })
+        .collect()
+}
+
+// Sections start with `== <name>` and hold a `<key>: <value>` line per entry. Newlines in a value
+// are escaped so that every entry stays on one line.
+pub fn render(sections: &[Section]) -> String {
+    sections
+        .iter()
+        .map(|section| {
+            let entries = section
+                .entries
+                .iter()
+                .map(|(key, value)| format!("{key}: {}\n", value.replace('\n', "\\n")))
+                .collect::<String>();
+            format!("== {}\n{entries}", section.name)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn parse(text: &str) -> Vec<Section> {
+    let mut sections: Vec<Section> = vec![];
+    for line in text.lines() {
+        if let Some(name) = line.strip_prefix("== ") {
+            sections.push(Section {
+                name: name.to_string(),
+                entries: vec![],
+            });
+        } else if let (Some(section), Some((key, value))) =
+            (sections.last_mut(), line.split_once(": "))
+        {
+            let value = value.replace("\\n", "\n");
+            section.entries.push((key.to_string(), value));
+        }
+    }
+    sections
+}
+
+#[test]
+fn test_snapshots_read_back_what_was_written() {
+    let sections = vec![
+        Section {
+            name: "sample.txt".to_string(),
+            entries: vec![
+                ("part 1".to_string(), "3".to_string()),
+                ("merged ranges".to_string(), "2".to_string()),
+            ],
+        },
+        Section {
+            name: "generated, seed 0, size 12".to_string(),
+            entries: vec![("error".to_string(), "expected a number\nor two".to_string())],
+        },
+    ];
+    let text = render(&sections);
+    assert_eq!(
+        text,
+        "== sample.txt\npart 1: 3\nmerged ranges: 2\n\n\
+         == generated, seed 0, size 12\nerror: expected a number\\nor two\n"
+    );
+    assert_eq!(parse(&text), sections);
+    assert_eq!(parse(""), vec![]);
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Difference {
+    pub section: String,
+    pub key: String,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let describe = |value: &Option<String>| match value {
+            Some(value) => format!("{value:?}"),
+            None => "nothing".to_string(),
+        };
+        write!(
+            f,
+            "{}, {}: expected {}, found {}",
+            self.section,
+            self.key,
+            describe(&self.expected),
+            describe(&self.actual)
+        )
+    }
+}
+
+// Entries are matched by section and key, so a missing or extra entry shows up on its own rather
+// than shifting every line after it.
+pub fn compare(expected: &[Section], actual: &[Section]) -> Vec<Difference> {
+    let lookup = |sections: &[Section], name: &str, key: &str| {
+        sections
+            .iter()
+            .find(|section| section.name == name)
+            .and_then(|section| section.entries.iter().find(|(k, _)| k == key))
+            .map(|(_, value)| value.clone())
+    };
+    let mut keys = vec![];
+    for section in expected.iter().chain(actual) {
+        for (key, _) in &section.entries {
+            if !keys.contains(&(&section.name, key)) {
+                keys.push((&section.name, key));
+            }
+        }
+    }
+    keys.into_iter()
+        .filter_map(|(name, key)| {
+            let (expected, actual) = (lookup(expected, name, key), lookup(actual, name, key));
+            (expected != actual).then(|| Difference {
+                section: name.clone(),
+                key: key.clone(),
+                expected,
+                actual,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_differences_name_the_input_and_entry_that_changed() {
+    let expected = parse("== a\npart 1: 3\npart 2: 4\n\n== b\npart 1: 5\n");
+    assert_eq!(compare(&expected, &expected), vec![]);
+
+    let actual = parse("== a\npart 1: 3\npart 2: 7\nrolls: 9\n");
+    let differences = compare(&expected, &actual)
+        .iter()
+        .map(Difference::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        differences,
+        [
+            "a, part 2: expected \"4\", found \"7\"",
+            "b, part 1: expected \"5\", found nothing",
+            "a, rolls: expected nothing, found \"9\"",
+        ]
+    );
+}
+
+// Records every day, or just `day`, and either writes the snapshots or compares them with the
+// ones on disk. Returns how many days did not match their snapshot; a day without one is skipped.
+pub fn check(
+    registry: &Registry,
+    snapshots: &Snapshots,
+    corpus: &Corpus,
+    day: Option<u8>,
+    update: bool,
+    output: &mut dyn Write,
+) -> std::io::Result<usize> {
+    let mut days = registry
+        .iter()
+        .map(|solver| solver.day)
+        .filter(|&d| day.is_none_or(|day| d == day))
+        .collect::<Vec<_>>();
+    days.dedup();
+
+    let mut failed = 0;
+    for day in days {
+        let path = snapshots.path(day);
+        let actual = record(registry, day, corpus);
+        if update {
+            std::fs::create_dir_all(snapshots.dir())?;
+            std::fs::write(&path, render(&actual))?;
+            writeln!(output, "Day {day:02}: wrote {}", path.display())?;
+            continue;
+        }
+        // A day scaffolded since the last `--update` has nothing to match yet.
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            writeln!(
+                output,
+                "Day {day:02}: skipped, no snapshot at {}, record one with --update",
+                path.display()
+            )?;
+            continue;
+        };
+        let differences = compare(&parse(&text), &actual);
+        if differences.is_empty() {
+            writeln!(output, "Day {day:02}: {} inputs match", actual.len())?;
+        } else {
+            writeln!(
+                output,
+                "Day {day:02}: {} difference(s) from {}",
+                differences.len(),
+                path.display()
+            )?;
+            for difference in differences {
+                writeln!(output, "  {difference}")?;
+            }
+            failed += 1;
+        }
+    }
+    Ok(failed)
+}
+
+#[test]
+fn test_snapshots_are_written_then_checked() {
+    use crate::Solver;
+    use crate::solver::test_solver;
+
+    let dir = std::env::temp_dir().join(format!("toolbox-snapshot-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("corpus/03")).unwrap();
+    std::fs::write(dir.join("corpus/03/sample.txt"), "a bc\n").unwrap();
+    let corpus = Corpus::new(dir.join("corpus"));
+    let snapshots = Snapshots::new(dir.join("snapshots"));
+    let registry = |part2: Solver| {
+        Registry::from_iter([[
+            Solver {
+                generate: |rng, size| Some(crate::random::bank(rng, size)),
+                ..test_solver!(3, 1, |input: &str| input.len())
+            },
+            part2,
+        ]
+        .as_slice()])
+    };
+    let before = registry(test_solver!(3, 2, |input: &str| input.len()));
+    let after = registry(test_solver!(3, 2, |_| 0));
+
+    let mut output = vec![];
+    let failed = check(&before, &snapshots, &corpus, None, false, &mut output);
+    assert_eq!(failed.unwrap(), 0);
+    assert!(
+        String::from_utf8(output)
+            .unwrap()
+            .starts_with("Day 03: skipped, no snapshot at ")
+    );
+
+    let mut output = vec![];
+    check(&before, &snapshots, &corpus, None, true, &mut output).unwrap();
+    let text = std::fs::read_to_string(snapshots.path(3)).unwrap();
+    assert!(text.starts_with("== sample.txt\npart 1: 5\n"), "{text}");
+    assert!(
+        text.contains("== generated, seed 2, size 12\npart 1: 12\n"),
+        "{text}"
+    );
+
+    let mut output = vec![];
+    let failed = check(&before, &snapshots, &corpus, Some(3), false, &mut output);
+    assert_eq!(failed.unwrap(), 0);
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "Day 03: 4 inputs match\n"
+    );
+
+    let mut output = vec![];
+    let failed = check(&after, &snapshots, &corpus, Some(3), false, &mut output);
+    assert_eq!(failed.unwrap(), 1);
+    let output = String::from_utf8(output).unwrap();
+    assert!(
+        output.contains("  sample.txt, part 2: expected \"5\", found \"0\"\n"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
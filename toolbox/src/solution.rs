@@ -0,0 +1,185 @@
+use crate::Solver;
+use crate::fuzz;
+use crate::random::Rng;
+use crate::solver::Parsed;
+use std::any::Any;
+use std::fmt::Display;
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input: 'static;
+    type Err: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err>;
+
+    // A part can still reject input that parsed, when only that part cannot use it.
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err>;
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err>;
+
+    // A random input of roughly `size` lines, ranges or cells, for benchmarking how a solution
+    // scales. Days without a generator return `None`.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    // Named figures about the parsed input, such as how many ranges survive merging, which
+    // snapshots record next to the answers to catch regressions the answers alone would hide.
+    fn summarize(_input: &Self::Input) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+
+    // Parsers for pieces of the input, such as a single line, which `aoc fuzz` tries on their own
+    // as well as through `parse`.
+    const FUZZ_TARGETS: &'static [fuzz::Target] = &[];
+}
+
+pub fn solve<S: Solution>(part: u8, input: &str) -> Result<String, S::Err> {
+    solve_parsed::<S>(part, &S::parse(input)?)
+}
+
+fn solve_parsed<S: Solution>(part: u8, input: &S::Input) -> Result<String, S::Err> {
+    match part {
+        1 => S::part1(input).map(|answer| answer.to_string()),
+        2 => S::part2(input).map(|answer| answer.to_string()),
+        _ => panic!("day {} has no part {part}", S::DAY),
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed, String> {
+    S::parse(input)
+        .map(|input| Box::new(input) as Parsed)
+        .map_err(|e| e.to_string())
+}
+
+fn solve_part<S: Solution, const PART: u8>(input: &dyn Any) -> Result<String, String> {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by the same solution");
+    solve_parsed::<S>(PART, input).map_err(|e| e.to_string())
+}
+
+fn summarize<S: Solution>(input: &dyn Any) -> Vec<(&'static str, String)> {
+    let input = input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by the same solution");
+    S::summarize(input)
+}
+
+pub const fn solvers<S: Solution>() -> [Solver; 2] {
+    [
+        Solver {
+            day: S::DAY,
+            part: 1,
+            parse: parse::<S>,
+            solve: solve_part::<S, 1>,
+            generate: S::generate,
+            summarize: summarize::<S>,
+            fuzz_targets: S::FUZZ_TARGETS,
+        },
+        Solver {
+            day: S::DAY,
+            part: 2,
+            parse: parse::<S>,
+            solve: solve_part::<S, 2>,
+            generate: S::generate,
+            summarize: summarize::<S>,
+            fuzz_targets: S::FUZZ_TARGETS,
+        },
+    ]
+}
+
+#[cfg(test)]
+struct WordCount;
+
+#[cfg(test)]
+impl Solution for WordCount {
+    const DAY: u8 = 7;
+
+    type Input = Vec<String>;
+    type Err = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Err> {
+        if input.is_empty() {
+            return Err("no words");
+        }
+        Ok(input.split_whitespace().map(str::to_string).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        Ok(input.len())
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Err> {
+        if input.iter().any(|word| word.contains('-')) {
+            return Err("a word with a dash");
+        }
+        Ok(input.join("-"))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(
+            (0..size)
+                .map(|_| crate::random::bank(rng, 3) + " ")
+                .collect(),
+        )
+    }
+
+    fn summarize(input: &Self::Input) -> Vec<(&'static str, String)> {
+        let longest = input.iter().map(String::len).max().unwrap_or_default();
+        vec![("longest word", longest.to_string())]
+    }
+}
+
+#[test]
+fn test_a_solution_provides_a_solver_for_each_part() {
+    const SOLVERS: &[Solver] = &solvers::<WordCount>();
+    assert_eq!(
+        SOLVERS
+            .iter()
+            .map(|solver| (solver.day, solver.part))
+            .collect::<Vec<_>>(),
+        vec![(7, 1), (7, 2)]
+    );
+    assert_eq!(SOLVERS[0].run("a b c").unwrap().answer, "3");
+    assert_eq!(SOLVERS[1].run("a b c").unwrap().answer, "a-b-c");
+}
+
+#[test]
+fn test_we_can_solve_either_part_of_a_solution() {
+    assert_eq!(solve::<WordCount>(1, "a b c"), Ok("3".to_string()));
+    assert_eq!(solve::<WordCount>(2, "a b c"), Ok("a-b-c".to_string()));
+    assert_eq!(solve::<WordCount>(1, ""), Err("no words"));
+    assert_eq!(solve::<WordCount>(1, "a-b c"), Ok("2".to_string()));
+    assert_eq!(solve::<WordCount>(2, "a-b c"), Err("a word with a dash"));
+}
+
+#[test]
+fn test_a_solver_reports_input_that_does_not_parse() {
+    assert_eq!(
+        solvers::<WordCount>()[0].run(""),
+        Err("no words".to_string())
+    );
+}
+
+#[test]
+fn test_a_solver_generates_input_with_its_solution() {
+    let solver = solvers::<WordCount>()[0];
+    let input = (solver.generate)(&mut Rng::new(0), 5).unwrap();
+    assert_eq!(solver.run(&input).unwrap().answer, "5");
+    assert_eq!(
+        (crate::solver::test_solver!(1, 1, |_| "").generate)(&mut Rng::new(0), 5),
+        None
+    );
+}
+
+#[test]
+fn test_a_solver_summarizes_input_with_its_solution() {
+    let solver = solvers::<WordCount>()[1];
+    let input = (solver.parse)("a bcd ef").unwrap();
+    assert_eq!(
+        (solver.summarize)(input.as_ref()),
+        [("longest word", "3".to_string())]
+    );
+}
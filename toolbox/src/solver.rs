@@ -0,0 +1,177 @@
+use crate::fuzz;
+use crate::random::Rng;
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+// A day's parsed input, whatever its type, so one registry can hold every day.
+pub type Parsed = Box<dyn Any>;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub parse: fn(&str) -> Result<Parsed, String>,
+    pub solve: fn(&dyn Any) -> Result<String, String>,
+    pub generate: fn(&mut Rng, usize) -> Option<String>,
+    pub summarize: fn(&dyn Any) -> Vec<(&'static str, String)>,
+    pub fuzz_targets: &'static [fuzz::Target],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl Solver {
+    pub fn run(&self, input: &str) -> Result<Answer, String> {
+        let start = Instant::now();
+        let parsed = (self.parse)(input)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = (self.solve)(parsed.as_ref())?;
+        let solve_time = start.elapsed();
+
+        Ok(Answer {
+            answer,
+            parse_time,
+            solve_time,
+        })
+    }
+}
+
+#[cfg(test)]
+macro_rules! test_solver {
+    ( $day:expr, $part:expr, $solve:expr ) => {
+        $crate::Solver {
+            day: $day,
+            part: $part,
+            parse: |input| Ok(Box::new(input.to_string())),
+            solve: |input| {
+                let input = input
+                    .downcast_ref::<String>()
+                    .expect("test input is a string");
+                Ok($solve(input.as_str()).to_string())
+            },
+            generate: |_, _| None,
+            summarize: |_| vec![],
+            fuzz_targets: &[],
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use test_solver;
+
+impl PartialEq for Solver {
+    fn eq(&self, other: &Self) -> bool {
+        (self.day, self.part) == (other.day, other.part)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Registry {
+    solvers: Vec<Solver>,
+}
+
+impl Registry {
+    pub fn register(&mut self, solvers: &[Solver]) {
+        for solver in solvers {
+            match self
+                .solvers
+                .binary_search_by_key(&(solver.day, solver.part), |s| (s.day, s.part))
+            {
+                Ok(i) => self.solvers[i] = *solver,
+                Err(i) => self.solvers.insert(i, *solver),
+            }
+        }
+    }
+
+    pub fn find(&self, day: u8, part: u8) -> Option<&Solver> {
+        self.solvers
+            .iter()
+            .find(|solver| solver.day == day && solver.part == part)
+    }
+
+    pub fn day(&self, day: u8) -> impl Iterator<Item = &Solver> {
+        self.solvers.iter().filter(move |solver| solver.day == day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Solver> {
+        self.solvers.iter()
+    }
+}
+
+impl<'a> FromIterator<&'a [Solver]> for Registry {
+    fn from_iter<I: IntoIterator<Item = &'a [Solver]>>(iter: I) -> Self {
+        let mut registry = Registry::default();
+        for solvers in iter {
+            registry.register(solvers);
+        }
+        registry
+    }
+}
+
+#[cfg(test)]
+const TEST_SOLVERS: &[Solver] = &[
+    test_solver!(2, 1, |input: &str| input.len()),
+    test_solver!(1, 2, |input: &str| input.to_uppercase()),
+    test_solver!(1, 1, |input: &str| input.to_string()),
+];
+
+#[test]
+fn test_a_solver_times_parsing_and_solving() {
+    let answer = TEST_SOLVERS[0].run("abc").unwrap();
+    assert_eq!(answer.answer, "3");
+
+    let unparsable = Solver {
+        parse: |_| Err("expected something".to_string()),
+        ..TEST_SOLVERS[0]
+    };
+    assert_eq!(unparsable.run("abc"), Err("expected something".to_string()));
+}
+
+#[test]
+fn test_the_registry_keeps_its_solvers_in_day_and_part_order() {
+    let registry = Registry::from_iter([TEST_SOLVERS]);
+    assert_eq!(
+        registry
+            .iter()
+            .map(|solver| (solver.day, solver.part))
+            .collect::<Vec<_>>(),
+        vec![(1, 1), (1, 2), (2, 1)]
+    );
+}
+
+#[test]
+fn test_the_registry_can_find_a_solver_by_day_and_part() {
+    let registry = Registry::from_iter([TEST_SOLVERS]);
+    assert_eq!(
+        registry.find(1, 2).unwrap().run("abc").unwrap().answer,
+        "ABC"
+    );
+    assert_eq!(registry.find(2, 1).unwrap().run("abc").unwrap().answer, "3");
+    assert!(registry.find(2, 2).is_none());
+    assert!(Registry::default().find(1, 1).is_none());
+}
+
+#[test]
+fn test_the_registry_can_list_the_parts_of_a_day() {
+    let registry = Registry::from_iter([TEST_SOLVERS]);
+    assert_eq!(registry.day(1).count(), 2);
+    assert_eq!(registry.day(2).count(), 1);
+    assert_eq!(registry.day(3).count(), 0);
+}
+
+#[test]
+fn test_registering_a_solver_twice_replaces_the_first() {
+    let replacement = test_solver!(1, 1, |_| "replaced");
+    let registry = Registry::from_iter([TEST_SOLVERS, &[replacement]]);
+    assert_eq!(registry.iter().count(), 3);
+    assert_eq!(
+        registry.find(1, 1).unwrap().run("abc").unwrap().answer,
+        "replaced"
+    );
+}
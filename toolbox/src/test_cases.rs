@@ -0,0 +1,224 @@
+use std::path::Path;
+
+#[derive(Debug, PartialEq)]
+pub struct TestCase {
+    pub name: String,
+    pub input: String,
+    pub expected_output: String,
+}
+
+pub fn load_test_cases(dir: &Path) -> Vec<TestCase> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut inputs = entries
+        .map(|entry| entry.expect("failed to read test case directory").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "in"))
+        .collect::<Vec<_>>();
+    inputs.sort();
+
+    inputs
+        .into_iter()
+        .map(|input_path| {
+            let output_path = input_path.with_extension("out");
+            TestCase {
+                name: input_path
+                    .file_stem()
+                    .expect("test case has a name")
+                    .to_string_lossy()
+                    .into_owned(),
+                input: std::fs::read_to_string(&input_path)
+                    .unwrap_or_else(|e| panic!("failed to read {}: {e}", input_path.display())),
+                expected_output: std::fs::read_to_string(&output_path)
+                    .unwrap_or_else(|e| panic!("failed to read {}: {e}", output_path.display())),
+            }
+        })
+        .collect()
+}
+
+// The optional third table lists inputs the executable must reject with a message.
+#[macro_export]
+macro_rules! executable_tests {
+    ( $binary:literal, $test_cases:expr, $malformed_inputs:expr $(,)? ) => {
+        $crate::executable_tests!($binary, $test_cases);
+
+        const MALFORMED_INPUTS: &[&str] = $malformed_inputs;
+
+        #[test]
+        fn test_that_the_executable_rejects_malformed_input_with_a_message() {
+            for &input in MALFORMED_INPUTS {
+                let output = $crate::execute(
+                    EXECUTABLE_UNDER_TEST,
+                    &[],
+                    input,
+                    Some(std::time::Duration::from_secs(10)),
+                )
+                .expect("failed to run the executable");
+                output.assert_failure();
+                assert_eq!(output.exit_code(), Some(1), "input: {input:?}");
+                assert_eq!(output.stdout, "", "input: {input:?}");
+                assert!(
+                    output.stderr.contains("failed to parse input"),
+                    "{}",
+                    output.stderr
+                );
+                assert!(!output.stderr.contains("panicked"), "{}", output.stderr);
+            }
+        }
+    };
+    ( $binary:literal, $test_cases:expr $(,)? ) => {
+        const EXECUTABLE_UNDER_TEST: &str = $crate::binary_path!($binary);
+
+        const TEST_CASES: &[(&str, &str)] = $test_cases;
+
+        #[test]
+        fn test_that_the_executable_exists() {
+            assert!(std::fs::exists(EXECUTABLE_UNDER_TEST).unwrap());
+        }
+
+        #[test]
+        fn test_that_the_executable_processes_the_input_and_produces_the_output() {
+            for &(input, expected_result) in TEST_CASES {
+                let child_output = $crate::invoke_executable(EXECUTABLE_UNDER_TEST, input);
+                assert_eq!(child_output, expected_result, "input: {input:?}");
+            }
+        }
+
+        #[test]
+        fn test_that_the_executable_labels_the_answer_for_each_input() {
+            let Some(&(first_input, first_expected_result)) = TEST_CASES.first() else {
+                return;
+            };
+            let dir =
+                std::env::temp_dir().join(format!("{}-inputs-{}", $binary, std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+
+            let mut args = vec!["-".to_string()];
+            let mut expected_output = format!("stdin: {first_expected_result}");
+            for (i, &(input, expected_result)) in TEST_CASES.iter().enumerate() {
+                let path = dir.join(format!("{i}.txt"));
+                std::fs::write(&path, input).unwrap();
+                args.push(path.display().to_string());
+                expected_output += &format!("{}: {expected_result}", path.display());
+            }
+
+            let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+            let child_output =
+                $crate::invoke_executable_with_args(EXECUTABLE_UNDER_TEST, &args, first_input);
+            assert_eq!(child_output, expected_output);
+
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_that_verbose_logging_leaves_the_answer_alone() {
+            for &(input, expected_result) in TEST_CASES {
+                let output = $crate::execute(EXECUTABLE_UNDER_TEST, &["-vv"], input, None)
+                    .expect("failed to run the executable");
+                output.assert_success();
+                assert_eq!(output.stdout, expected_result, "input: {input:?}");
+                assert!(
+                    output.stderr.lines().all(|line| line.starts_with("level=")),
+                    "{}",
+                    output.stderr
+                );
+            }
+        }
+
+        #[test]
+        fn test_that_the_executable_writes_json_answers() {
+            for &(input, expected_result) in TEST_CASES {
+                let child_output = $crate::invoke_executable_with_args(
+                    EXECUTABLE_UNDER_TEST,
+                    &["--format", "json"],
+                    input,
+                );
+                assert_eq!(child_output.lines().count(), 1, "input: {input:?}");
+                for field in [
+                    format!("\"answer\":{:?}", expected_result.trim_end()),
+                    format!("\"input_hash\":\"{}\"", $crate::input_hash(input)),
+                    "\"parse_time_ns\":".to_string(),
+                    "\"solve_time_ns\":".to_string(),
+                ] {
+                    assert!(
+                        child_output.contains(&field),
+                        "{child_output} lacks {field}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_that_the_executable_processes_the_case_files() {
+            let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("cases")
+                .join($binary);
+            for test_case in $crate::load_test_cases(&dir) {
+                let child_output =
+                    $crate::invoke_executable(EXECUTABLE_UNDER_TEST, &test_case.input);
+                assert_eq!(
+                    child_output, test_case.expected_output,
+                    "case: {}",
+                    test_case.name
+                );
+            }
+        }
+    };
+}
+
+#[test]
+fn test_we_can_load_test_cases_from_a_directory() {
+    let dir = std::env::temp_dir().join(format!("toolbox-test-cases-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("b.in"), "2\n").unwrap();
+    std::fs::write(dir.join("b.out"), "4\n").unwrap();
+    std::fs::write(dir.join("a.in"), "1\n").unwrap();
+    std::fs::write(dir.join("a.out"), "2\n").unwrap();
+    std::fs::write(dir.join("README"), "ignored").unwrap();
+
+    assert_eq!(
+        load_test_cases(&dir),
+        vec![
+            TestCase {
+                name: "a".to_string(),
+                input: "1\n".to_string(),
+                expected_output: "2\n".to_string()
+            },
+            TestCase {
+                name: "b".to_string(),
+                input: "2\n".to_string(),
+                expected_output: "4\n".to_string()
+            },
+        ]
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_a_missing_test_case_directory_has_no_cases() {
+    assert_eq!(load_test_cases(Path::new("/does/not/exist")), vec![]);
+}
+
+#[cfg(test)]
+struct TempDir(std::path::PathBuf);
+
+#[cfg(test)]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "lonely.out")]
+fn test_an_input_without_an_expected_output_is_an_error() {
+    let dir =
+        TempDir(std::env::temp_dir().join(format!("toolbox-lonely-case-{}", std::process::id())));
+    std::fs::create_dir_all(&dir.0).unwrap();
+    std::fs::write(dir.0.join("lonely.in"), "1\n").unwrap();
+
+    load_test_cases(&dir.0);
+}
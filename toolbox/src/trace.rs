@@ -0,0 +1,151 @@
+use std::cell::RefCell;
+use std::fmt::{Display, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+        }
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Clone, Debug)]
+pub struct Capture {
+    verbosity: u8,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Capture>> = const { RefCell::new(None) };
+}
+
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    let verbosity = CAPTURED
+        .with_borrow(|captured| captured.as_ref().map(|capture| capture.verbosity))
+        .unwrap_or_else(|| VERBOSITY.load(Ordering::Relaxed));
+    verbosity >= level as u8
+}
+
+pub fn verbosity_flag(arg: &str) -> Option<u8> {
+    let vs = arg.strip_prefix('-')?;
+    (!vs.is_empty() && vs.len() <= 2 && vs.bytes().all(|b| b == b'v')).then_some(vs.len() as u8)
+}
+
+#[test]
+fn test_we_recognise_verbosity_flags() {
+    assert_eq!(verbosity_flag("-v"), Some(1));
+    assert_eq!(verbosity_flag("-vv"), Some(2));
+    assert_eq!(verbosity_flag("-vvv"), None);
+    assert_eq!(verbosity_flag("-"), None);
+    assert_eq!(verbosity_flag("--verbose"), None);
+    assert_eq!(verbosity_flag("v"), None);
+}
+
+pub fn format_event(level: Level, event: &str, fields: &[(&str, &dyn Display)]) -> String {
+    let mut line = format!("level={} event={event}", level.name());
+    for (key, value) in fields {
+        let value = value.to_string();
+        if value.is_empty() || value.contains([' ', '"', '=']) {
+            write!(line, " {key}={value:?}")
+        } else {
+            write!(line, " {key}={value}")
+        }
+        .expect("writing to a string cannot fail");
+    }
+    line
+}
+
+#[test]
+fn test_events_are_written_as_logfmt() {
+    assert_eq!(
+        format_event(
+            Level::Info,
+            "removal_wave",
+            &[("wave", &1), ("removed", &13)]
+        ),
+        "level=info event=removal_wave wave=1 removed=13"
+    );
+    assert_eq!(
+        format_event(Level::Debug, "memo_hit", &[("node", &"a b"), ("name", &"")]),
+        "level=debug event=memo_hit node=\"a b\" name=\"\""
+    );
+}
+
+pub fn emit(level: Level, event: &str, fields: &[(&str, &dyn Display)]) {
+    let line = format_event(level, event, fields);
+    CAPTURED.with_borrow(|captured| match captured {
+        Some(capture) => capture
+            .events
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(line),
+        None => eprintln!("{line}"),
+    });
+}
+
+pub fn current_capture() -> Option<Capture> {
+    CAPTURED.with_borrow(Clone::clone)
+}
+
+pub fn with_capture<T>(capture: Option<Capture>, f: impl FnOnce() -> T) -> T {
+    let outer = CAPTURED.replace(capture);
+    let result = f();
+    CAPTURED.set(outer);
+    result
+}
+
+pub fn capture<T>(verbosity: u8, f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let events = Arc::new(Mutex::new(vec![]));
+    let capture = Capture {
+        verbosity,
+        events: Arc::clone(&events),
+    };
+    let result = with_capture(Some(capture), f);
+    let events = std::mem::take(&mut *events.lock().unwrap_or_else(PoisonError::into_inner));
+    (result, events)
+}
+
+#[macro_export]
+macro_rules! event {
+    ( $level:ident, $event:expr $(, $key:ident = $value:expr )* $(,)? ) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::emit(
+                $crate::trace::Level::$level,
+                $event,
+                &[ $( (stringify!($key), &$value as &dyn ::std::fmt::Display) ),* ],
+            );
+        }
+    };
+}
+
+#[test]
+fn test_only_events_at_the_chosen_verbosity_are_emitted() {
+    let emit_both = || {
+        crate::event!(Info, "wave", removed = 3);
+        crate::event!(Debug, "hit", key = "abc");
+    };
+    assert_eq!(capture(0, emit_both).1, Vec::<String>::new());
+    assert_eq!(capture(1, emit_both).1, ["level=info event=wave removed=3"]);
+    assert_eq!(
+        capture(2, emit_both).1,
+        [
+            "level=info event=wave removed=3",
+            "level=debug event=hit key=abc"
+        ]
+    );
+}
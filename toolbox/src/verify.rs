@@ -0,0 +1,421 @@
+use crate::input_store::InputStore;
+#[cfg(test)]
+use crate::solver::test_solver;
+use crate::{Registry, Solver};
+use serde::{Deserialize, Deserializer};
+use std::fmt::Display;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub part: u8,
+    #[serde(default)]
+    pub input: Option<PathBuf>,
+    #[serde(default)]
+    pub variant: Option<String>,
+    #[serde(deserialize_with = "string_or_integer")]
+    pub answer: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrInteger {
+    String(String),
+    Integer(i64),
+}
+
+fn string_or_integer<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(match StringOrInteger::deserialize(deserializer)? {
+        StringOrInteger::String(answer) => answer,
+        StringOrInteger::Integer(answer) => answer.to_string(),
+    })
+}
+
+#[derive(Deserialize)]
+struct AnswersFile {
+    #[serde(default, rename = "answer")]
+    answers: Vec<ExpectedAnswer>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseAnswersError(pub String);
+
+impl Display for ParseAnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+pub fn parse_answers(s: &str) -> Result<Vec<ExpectedAnswer>, ParseAnswersError> {
+    toml::from_str::<AnswersFile>(s)
+        .map(|file| file.answers)
+        .map_err(|e| ParseAnswersError(e.to_string()))
+}
+
+#[test]
+fn test_we_can_parse_an_answers_file() {
+    assert_eq!(parse_answers(""), Ok(vec![]));
+    assert_eq!(
+        parse_answers(
+            r#"
+[[answer]]
+day = 1
+part = 2
+input = "input/01.txt"
+answer = 6
+
+[[answer]]
+day = 5
+part = 1
+variant = "sample"
+answer = "3"
+"#
+        ),
+        Ok(vec![
+            ExpectedAnswer {
+                day: 1,
+                part: 2,
+                input: Some(PathBuf::from("input/01.txt")),
+                variant: None,
+                answer: "6".to_string()
+            },
+            ExpectedAnswer {
+                day: 5,
+                part: 1,
+                input: None,
+                variant: Some("sample".to_string()),
+                answer: "3".to_string()
+            }
+        ])
+    );
+    assert!(parse_answers("[[answer]]\nday = 1\n").is_err());
+    assert!(parse_answers("[[answer]]\nday = 1\npart = 1\ninput = \"x\"\nanswer = 1.5\n").is_err());
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Panicked,
+    Unparsable(String),
+    MissingAnswer,
+    MissingSolver,
+    MissingInput(String),
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub part: u8,
+    pub input: Option<PathBuf>,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+impl Verification {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Pass
+    }
+
+    pub fn failed(&self) -> bool {
+        matches!(
+            self.outcome,
+            Outcome::Fail { .. } | Outcome::Panicked | Outcome::Unparsable(_)
+        )
+    }
+}
+
+// Answers naming an input file are read relative to the answers file; the rest come from
+// the input store.
+fn input_path(expected: &ExpectedAnswer) -> PathBuf {
+    expected
+        .input
+        .clone()
+        .unwrap_or_else(|| InputStore::relative_path(expected.day, expected.variant.as_deref()))
+}
+
+fn read_input(
+    expected: &ExpectedAnswer,
+    base_dir: &Path,
+    store: &InputStore,
+) -> Result<String, String> {
+    match &expected.input {
+        Some(path) => std::fs::read_to_string(base_dir.join(path)).map_err(|e| e.to_string()),
+        None => store
+            .read(expected.day, expected.variant.as_deref())
+            .map_err(|e| e.to_string()),
+    }
+}
+
+fn check(
+    solver: &Solver,
+    expected: &ExpectedAnswer,
+    base_dir: &Path,
+    store: &InputStore,
+) -> Verification {
+    let mut verification = Verification {
+        day: expected.day,
+        part: expected.part,
+        input: Some(input_path(expected)),
+        outcome: Outcome::Pass,
+        elapsed: Duration::ZERO,
+    };
+
+    let input = match read_input(expected, base_dir, store) {
+        Ok(input) => input,
+        Err(message) => {
+            verification.outcome = Outcome::MissingInput(message);
+            return verification;
+        }
+    };
+
+    let start = Instant::now();
+    let actual = std::panic::catch_unwind(|| solver.run(&input));
+    verification.elapsed = start.elapsed();
+
+    verification.outcome = match actual {
+        Ok(Ok(actual)) if actual.answer == expected.answer => Outcome::Pass,
+        Ok(Ok(actual)) => Outcome::Fail {
+            expected: expected.answer.clone(),
+            actual: actual.answer,
+        },
+        Ok(Err(message)) => Outcome::Unparsable(message),
+        Err(_) => Outcome::Panicked,
+    };
+    verification
+}
+
+pub fn verify(
+    registry: &Registry,
+    answers: &[ExpectedAnswer],
+    base_dir: &Path,
+    store: &InputStore,
+) -> Vec<Verification> {
+    let mut verifications = vec![];
+
+    for solver in registry.iter() {
+        let mut expected_answers = answers
+            .iter()
+            .filter(|expected| expected.day == solver.day && expected.part == solver.part)
+            .peekable();
+
+        if expected_answers.peek().is_none() {
+            verifications.push(Verification {
+                day: solver.day,
+                part: solver.part,
+                input: None,
+                outcome: Outcome::MissingAnswer,
+                elapsed: Duration::ZERO,
+            });
+        }
+
+        for expected in expected_answers {
+            verifications.push(check(solver, expected, base_dir, store));
+        }
+    }
+
+    for expected in answers {
+        if registry.find(expected.day, expected.part).is_none() {
+            verifications.push(Verification {
+                day: expected.day,
+                part: expected.part,
+                input: Some(input_path(expected)),
+                outcome: Outcome::MissingSolver,
+                elapsed: Duration::ZERO,
+            });
+        }
+    }
+
+    verifications
+}
+
+pub fn write_report(verifications: &[Verification], output: &mut dyn Write) -> std::io::Result<()> {
+    for verification in verifications {
+        let (status, detail) = match &verification.outcome {
+            Outcome::Pass => ("PASS", String::new()),
+            Outcome::Fail { expected, actual } => {
+                ("FAIL", format!("expected {expected}, got {actual}"))
+            }
+            Outcome::Panicked => ("FAIL", "solver panicked".to_string()),
+            Outcome::Unparsable(message) => ("FAIL", format!("failed to parse input: {message}")),
+            Outcome::MissingAnswer => ("MISSING", "no expected answer".to_string()),
+            Outcome::MissingSolver => ("MISSING", "no solver registered".to_string()),
+            Outcome::MissingInput(message) => ("MISSING", format!("no input: {message}")),
+        };
+        let input = verification
+            .input
+            .as_deref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        let elapsed = match verification.outcome {
+            Outcome::Pass | Outcome::Fail { .. } | Outcome::Panicked | Outcome::Unparsable(_) => {
+                format!("{:.3?}", verification.elapsed)
+            }
+            _ => "-".to_string(),
+        };
+        let line = format!(
+            "Day {:02} part {}  {status:<7}  {elapsed:>12}  {input:<16}  {detail}",
+            verification.day, verification.part
+        );
+        writeln!(output, "{}", line.trim_end())?;
+    }
+
+    let passed = verifications.iter().filter(|v| v.passed()).count();
+    let failed = verifications.iter().filter(|v| v.failed()).count();
+    writeln!(
+        output,
+        "{passed} passed, {failed} failed, {} missing",
+        verifications.len() - passed - failed
+    )
+}
+
+#[cfg(test)]
+fn test_registry() -> Registry {
+    Registry::from_iter([[
+        test_solver!(1, 1, |input: &str| input.lines().count()),
+        test_solver!(1, 2, |input: &str| input.len()),
+        test_solver!(2, 1, |_| -> &str { panic!("not solved yet") }),
+    ]
+    .as_slice()])
+}
+
+#[test]
+fn test_we_can_verify_solvers_against_expected_answers() {
+    let dir = std::env::temp_dir().join(format!("toolbox-verify-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("01.txt"), "a\nb\n").unwrap();
+    std::fs::write(dir.join("02.txt"), "").unwrap();
+    std::fs::create_dir_all(dir.join("store/2025")).unwrap();
+    std::fs::write(dir.join("store/2025/01.sample.txt"), "a\n").unwrap();
+
+    let answers = parse_answers(
+        r#"
+[[answer]]
+day = 1
+part = 1
+input = "01.txt"
+answer = 2
+
+[[answer]]
+day = 1
+part = 1
+variant = "sample"
+answer = 1
+
+[[answer]]
+day = 1
+part = 2
+input = "01.txt"
+answer = 5
+
+[[answer]]
+day = 2
+part = 1
+input = "02.txt"
+answer = 0
+
+[[answer]]
+day = 3
+part = 1
+input = "03.txt"
+answer = 0
+"#,
+    )
+    .unwrap();
+
+    let outcomes = verify(
+        &test_registry(),
+        &answers,
+        &dir,
+        &InputStore::new(dir.join("store")),
+    )
+    .into_iter()
+    .map(|v| (v.day, v.part, v.outcome))
+    .collect::<Vec<_>>();
+    assert_eq!(
+        outcomes,
+        vec![
+            (1, 1, Outcome::Pass),
+            (1, 1, Outcome::Pass),
+            (
+                1,
+                2,
+                Outcome::Fail {
+                    expected: "5".to_string(),
+                    actual: "4".to_string()
+                }
+            ),
+            (2, 1, Outcome::Panicked),
+            (3, 1, Outcome::MissingSolver),
+        ]
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_solvers_without_answers_or_inputs_are_reported_missing() {
+    let answers = parse_answers(
+        r#"
+[[answer]]
+day = 1
+part = 1
+input = "does-not-exist.txt"
+answer = 2
+
+[[answer]]
+day = 1
+part = 2
+answer = 2
+"#,
+    )
+    .unwrap();
+
+    let verifications = verify(
+        &test_registry(),
+        &answers,
+        Path::new("/nonexistent"),
+        &InputStore::new("/nonexistent"),
+    );
+    assert!(matches!(verifications[0].outcome, Outcome::MissingInput(_)));
+    assert!(
+        matches!(&verifications[1].outcome, Outcome::MissingInput(message) if message.contains("no input for day 1"))
+    );
+    assert_eq!(verifications[1].input, Some(PathBuf::from("2025/01.txt")));
+    assert_eq!(verifications[2].outcome, Outcome::MissingAnswer);
+
+    let mut report = vec![];
+    write_report(&verifications, &mut report).unwrap();
+    let report = String::from_utf8(report).unwrap();
+    assert!(report.contains("no expected answer"));
+    assert!(report.ends_with("0 passed, 0 failed, 3 missing\n"));
+}
+
+#[test]
+fn test_input_that_does_not_parse_fails_verification() {
+    let registry = Registry::from_iter([[Solver {
+        parse: |_| Err("expected a grid".to_string()),
+        ..test_solver!(4, 1, |_| "")
+    }]
+    .as_slice()]);
+    let answers =
+        parse_answers("[[answer]]\nday = 4\npart = 1\ninput = \"Cargo.toml\"\nanswer = 1\n")
+            .unwrap();
+
+    let verifications = verify(
+        &registry,
+        &answers,
+        Path::new(env!("CARGO_MANIFEST_DIR")),
+        &InputStore::new("/nonexistent"),
+    );
+    assert_eq!(
+        verifications[0].outcome,
+        Outcome::Unparsable("expected a grid".to_string())
+    );
+    assert!(verifications[0].failed());
+}